@@ -1,43 +1,73 @@
 //! Build script for compiling protobuf definitions
+//!
+//! Bindings are generated into `OUT_DIR` from the `.proto` files in `proto/`,
+//! which also vendors the well-known Google types they import. With the
+//! `pregenerated` feature the checked-in bindings in `src/proto/` are used
+//! instead, so neither `protoc` nor the proto sources are needed.
+//!
+//! Set `TERANODE_UPDATE_BINDINGS=1` to refresh the checked-in bindings after
+//! changing the protos.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Teranode service definitions that must be present in `proto/`
+const PROTO_FILES: &[&str] = &["model.proto", "blockchain_api.proto", "p2p_api.proto"];
+
+/// Generated modules, as included by `src/lib.rs`
+const BINDINGS: &[&str] = &["model.rs", "blockchain_api.rs", "p2p_api.rs"];
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let proto_dir = PathBuf::from("proto");
+    println!("cargo:rerun-if-changed=proto");
+    println!("cargo:rerun-if-env-changed=TERANODE_UPDATE_BINDINGS");
 
-    // Check if proto directory exists and contains .proto files
-    if !proto_dir.exists() {
-        println!("cargo:warning=Proto directory not found. Create 'proto' directory and add .proto files.");
+    if std::env::var_os("CARGO_FEATURE_PREGENERATED").is_some() {
         return Ok(());
     }
 
-    // Collect all .proto files in the proto directory
-    let proto_files: Vec<PathBuf> = std::fs::read_dir(&proto_dir)?
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            let path = entry.path();
-            if path.extension()? == "proto" {
-                Some(path)
-            } else {
-                None
-            }
-        })
+    let proto_dir = PathBuf::from("proto");
+    let missing: Vec<&str> = PROTO_FILES
+        .iter()
+        .copied()
+        .filter(|file| !proto_dir.join(file).is_file())
         .collect();
-
-    if proto_files.is_empty() {
-        println!("cargo:warning=No .proto files found in proto directory.");
-        println!("cargo:warning=Add protobuf files from Teranode to crates/teranode-client/proto/");
-        return Ok(());
+    if !missing.is_empty() {
+        return Err(format!(
+            "Missing proto files in {}: {}. Copy them from the Teranode repository, \
+             or enable the `pregenerated` feature to use the checked-in bindings.",
+            std::fs::canonicalize(&proto_dir)
+                .unwrap_or(proto_dir.clone())
+                .display(),
+            missing.join(", ")
+        )
+        .into());
     }
 
-    println!("cargo:rerun-if-changed=proto");
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
+    let protos: Vec<PathBuf> = PROTO_FILES.iter().map(|f| proto_dir.join(f)).collect();
 
     // Compile proto files with tonic
     tonic_build::configure()
-        .build_server(false) // We only need the client
+        .build_server(true) // Server stubs back the mock server in `testing`
         .build_client(true)
-        .out_dir("src/proto") // Output generated code to src/proto
-        .compile_protos(&proto_files, &[proto_dir])?;
+        .out_dir(&out_dir)
+        .compile_protos(&protos, &[proto_dir])?;
+
+    if std::env::var_os("TERANODE_UPDATE_BINDINGS").is_some() {
+        update_bindings(&out_dir, Path::new("src/proto"))?;
+    }
 
     Ok(())
 }
+
+/// Copy freshly generated bindings over the checked-in ones
+fn update_bindings(out_dir: &Path, target: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(target)?;
+    for file in BINDINGS {
+        std::fs::copy(out_dir.join(file), target.join(file))?;
+    }
+    println!(
+        "cargo:warning=Updated checked-in bindings in {}",
+        target.display()
+    );
+    Ok(())
+}
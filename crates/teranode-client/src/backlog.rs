@@ -0,0 +1,153 @@
+//! Post-processing backlog
+//!
+//! After a block is validated, Teranode still has to mark it as mined
+//! (`mined_set`) and record its subtrees (`subtrees_set`). Blocks waiting on
+//! either step are returned by GetBlocksMinedNotSet and
+//! GetBlocksSubtreesNotSet. A backlog that keeps growing, or whose oldest
+//! block keeps getting older, means the processing pipeline is falling behind.
+
+use crate::block::Block;
+use crate::header::unix_time;
+use std::time::{Duration, SystemTime};
+
+/// Blocks waiting on one post-processing step, in height order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PendingBlocks {
+    pub blocks: Vec<Block>,
+}
+
+impl PendingBlocks {
+    /// Collect pending blocks, sorting them by height
+    pub fn new(mut blocks: Vec<Block>) -> Self {
+        blocks.sort_by_key(|b| b.height);
+        Self { blocks }
+    }
+
+    /// Number of pending blocks
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Whether there are no pending blocks
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Lowest and highest pending heights
+    pub fn height_range(&self) -> Option<(u32, u32)> {
+        Some((self.blocks.first()?.height, self.blocks.last()?.height))
+    }
+
+    /// The block with the earliest header timestamp
+    pub fn oldest(&self) -> Option<&Block> {
+        self.blocks.iter().min_by_key(|b| b.header.timestamp())
+    }
+
+    /// Age of the oldest pending block at `now`, measured from its header timestamp
+    ///
+    /// Header timestamps may run slightly ahead of the local clock, in which
+    /// case the age is zero.
+    pub fn max_age(&self, now: SystemTime) -> Option<Duration> {
+        self.oldest().map(|b| block_age(b, now))
+    }
+}
+
+/// Blocks waiting on each post-processing step
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Backlog {
+    /// Blocks not yet marked as mined
+    pub mined_not_set: PendingBlocks,
+
+    /// Blocks whose subtrees have not been recorded
+    pub subtrees_not_set: PendingBlocks,
+}
+
+impl Backlog {
+    /// Whether nothing is waiting on either step
+    pub fn is_empty(&self) -> bool {
+        self.mined_not_set.is_empty() && self.subtrees_not_set.is_empty()
+    }
+
+    /// Age of the oldest block waiting on either step
+    pub fn max_age(&self, now: SystemTime) -> Option<Duration> {
+        self.mined_not_set
+            .max_age(now)
+            .max(self.subtrees_not_set.max_age(now))
+    }
+}
+
+/// Time since a block's header timestamp
+pub fn block_age(block: &Block, now: SystemTime) -> Duration {
+    now.duration_since(unix_time(block.header.timestamp()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{serve_with_blocks, MockTeranode, MOCK_BLOCK_INTERVAL};
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn test_pending_blocks() {
+        let mock = MockTeranode::with_blocks(4);
+        let block = |height| mock.block_at(height).unwrap().block;
+        let pending = PendingBlocks::new(vec![block(3), block(1), block(2)]);
+
+        assert_eq!(pending.len(), 3);
+        assert_eq!(pending.height_range(), Some((1, 3)));
+        assert_eq!(pending.oldest().unwrap().height, 1);
+
+        let now = unix_time(block(4).header.timestamp());
+        assert_eq!(
+            pending.max_age(now),
+            Some(Duration::from_secs(3 * MOCK_BLOCK_INTERVAL as u64))
+        );
+        assert_eq!(block_age(&block(4), UNIX_EPOCH), Duration::ZERO);
+
+        let backlog = Backlog {
+            mined_not_set: PendingBlocks::default(),
+            subtrees_not_set: pending,
+        };
+        assert!(!backlog.is_empty());
+        assert_eq!(
+            backlog.max_age(now),
+            Some(Duration::from_secs(3 * MOCK_BLOCK_INTERVAL as u64))
+        );
+        assert!(PendingBlocks::default().height_range().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_backlog_against_mock() {
+        let (mock, _server, mut client) = serve_with_blocks(5).await;
+        for height in [3, 4] {
+            mock.update_meta(height, |meta| meta.mined_set = false);
+        }
+        mock.update_meta(5, |meta| meta.subtrees_set = false);
+
+        let backlog = client.get_backlog().await.unwrap();
+        assert_eq!(backlog.mined_not_set.height_range(), Some((3, 4)));
+        assert_eq!(backlog.subtrees_not_set.len(), 1);
+        assert_eq!(backlog.subtrees_not_set.blocks[0].hash(), mock.tip().hash());
+
+        let hash = mock.block_at(3).unwrap().hash();
+        assert!(!client.get_block_is_mined(&hash).await.unwrap());
+        client.set_block_mined_set(&hash).await.unwrap();
+        assert!(client.get_block_is_mined(&hash).await.unwrap());
+        client
+            .set_block_subtrees_set(&mock.tip().hash())
+            .await
+            .unwrap();
+
+        let backlog = client.get_backlog().await.unwrap();
+        assert_eq!(backlog.mined_not_set.len(), 1);
+        assert!(backlog.subtrees_not_set.is_empty());
+
+        client.set_block_processed_at(&hash).await.unwrap();
+        let header = client.get_block_header(&hash).await.unwrap();
+        assert!(header.meta.processed_at.is_some());
+        client.clear_block_processed_at(&hash).await.unwrap();
+        let header = client.get_block_header(&hash).await.unwrap();
+        assert!(header.meta.processed_at.is_none());
+    }
+}
@@ -0,0 +1,262 @@
+//! Decoded block types
+//!
+//! Teranode does not return full blocks with every transaction. A block is
+//! described by its header, coinbase transaction and the list of subtree hashes
+//! that together make up the rest of the block's transactions.
+
+use crate::error::{Result, TeranodeError};
+use crate::header::decode_header;
+use crate::proto::blockchain_api::GetBlockResponse;
+use bitcoinsv::bitcoin::{
+    varint_decode, varint_encode, BlockHash, BlockHeader, Encodable, Hash, Tx,
+};
+
+/// A block as stored by Teranode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// The block header
+    pub header: BlockHeader,
+
+    /// The coinbase transaction
+    pub coinbase_tx: Tx,
+
+    /// Total number of transactions in the block, including the coinbase
+    pub transaction_count: u64,
+
+    /// Total size of the block in bytes
+    pub size_in_bytes: u64,
+
+    /// Hashes of the subtrees containing the block's transactions
+    pub subtree_hashes: Vec<Hash>,
+
+    /// Block height, if known
+    pub height: u32,
+
+    /// Internal block identifier, only present when the block was fetched individually
+    pub id: Option<u32>,
+}
+
+impl Block {
+    /// Hash of the block
+    pub fn hash(&self) -> BlockHash {
+        self.header.hash()
+    }
+
+    /// Decode a block in Teranode's serialized form, as returned by GetBlocks,
+    /// GetBlocksByHeight and similar calls
+    ///
+    /// The layout is the 80-byte header, varint transaction count, varint size
+    /// in bytes, varint subtree count followed by the 32-byte subtree hashes,
+    /// the coinbase transaction and finally an optional varint block height.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() <= BlockHeader::SIZE as usize {
+            return Err(decode_error(format!(
+                "Serialized block too short: {} bytes",
+                bytes.len()
+            )));
+        }
+
+        let (header_bytes, mut cursor) = bytes.split_at(BlockHeader::SIZE as usize);
+        let header = decode_header(header_bytes)?;
+
+        let transaction_count = read_varint(&mut cursor, "transaction count")?;
+        let size_in_bytes = read_varint(&mut cursor, "block size")?;
+        let subtree_count = read_varint(&mut cursor, "subtree count")?;
+
+        let subtree_len = (subtree_count as usize)
+            .checked_mul(Hash::SIZE as usize)
+            .filter(|len| *len <= cursor.len())
+            .ok_or_else(|| {
+                decode_error(format!(
+                    "Subtree count {} exceeds block data",
+                    subtree_count
+                ))
+            })?;
+        let (subtree_bytes, rest) = cursor.split_at(subtree_len);
+        let subtree_hashes = subtree_bytes
+            .chunks_exact(Hash::SIZE as usize)
+            .map(Hash::from_slice)
+            .collect();
+        cursor = rest;
+
+        let coinbase_tx = read_coinbase(&mut cursor)?;
+
+        let height = if cursor.is_empty() {
+            0
+        } else {
+            read_varint(&mut cursor, "block height")? as u32
+        };
+
+        Ok(Self {
+            header,
+            coinbase_tx,
+            transaction_count,
+            size_in_bytes,
+            subtree_hashes,
+            height,
+            id: None,
+        })
+    }
+
+    /// Serialize in the layout read by [`from_bytes`](Self::from_bytes),
+    /// always including the block height
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.header.raw.to_vec();
+        write_varint(&mut bytes, self.transaction_count);
+        write_varint(&mut bytes, self.size_in_bytes);
+        write_varint(&mut bytes, self.subtree_hashes.len() as u64);
+        for hash in &self.subtree_hashes {
+            bytes.extend_from_slice(&hash.raw);
+        }
+        bytes.extend_from_slice(&encode_tx(&self.coinbase_tx));
+        write_varint(&mut bytes, self.height as u64);
+        bytes
+    }
+}
+
+impl TryFrom<GetBlockResponse> for Block {
+    type Error = TeranodeError;
+
+    fn try_from(response: GetBlockResponse) -> Result<Self> {
+        let header = decode_header(&response.header)?;
+
+        let coinbase_tx = read_coinbase(&mut response.coinbase_tx.as_slice())?;
+
+        let subtree_hashes = response
+            .subtree_hashes
+            .iter()
+            .map(|hash| {
+                if hash.len() != Hash::SIZE as usize {
+                    return Err(decode_error(format!(
+                        "Invalid subtree hash length: {}",
+                        hash.len()
+                    )));
+                }
+                Ok(Hash::from_slice(hash))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            header,
+            coinbase_tx,
+            transaction_count: response.transaction_count,
+            size_in_bytes: response.size_in_bytes,
+            subtree_hashes,
+            height: response.height,
+            id: Some(response.id),
+        })
+    }
+}
+
+/// Decode a list of serialized blocks
+pub fn decode_blocks(blocks: &[Vec<u8>]) -> Result<Vec<Block>> {
+    blocks.iter().map(|b| Block::from_bytes(b)).collect()
+}
+
+fn read_varint(cursor: &mut &[u8], field: &str) -> Result<u64> {
+    if cursor.is_empty() {
+        return Err(decode_error(format!("Missing {}", field)));
+    }
+    varint_decode(cursor).map_err(|e| decode_error(format!("Invalid {}: {}", field, e)))
+}
+
+fn read_coinbase(cursor: &mut &[u8]) -> Result<Tx> {
+    if cursor.is_empty() {
+        return Err(decode_error("Missing coinbase transaction"));
+    }
+    Tx::from_binary(cursor).map_err(|e| decode_error(format!("Invalid coinbase: {}", e)))
+}
+
+fn write_varint(bytes: &mut Vec<u8>, value: u64) {
+    varint_encode(bytes, value).expect("writing to a Vec cannot fail");
+}
+
+/// Serialize a transaction
+pub(crate) fn encode_tx(tx: &Tx) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(tx.encoded_size() as usize);
+    tx.to_binary(&mut bytes)
+        .expect("writing to a Vec cannot fail");
+    bytes
+}
+
+fn decode_error(message: impl Into<String>) -> TeranodeError {
+    TeranodeError::DecodeError(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoinsv::bitcoin::{BlockchainId, FromHex};
+
+    // Coinbase transaction of the mainnet genesis block
+    const GENESIS_COINBASE: &str = "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+
+    fn serialized_block(subtrees: &[[u8; 32]], height: Option<u8>) -> Vec<u8> {
+        let mut bytes = BlockHeader::get_genesis(BlockchainId::Main).raw.to_vec();
+        bytes.push(1); // transaction count
+        bytes.push(0xfd); // size in bytes, 3-byte varint
+        bytes.extend_from_slice(&285u16.to_le_bytes());
+        bytes.push(subtrees.len() as u8);
+        for subtree in subtrees {
+            bytes.extend_from_slice(subtree);
+        }
+        bytes.extend_from_slice(&Vec::<u8>::from_hex(GENESIS_COINBASE).unwrap());
+        if let Some(height) = height {
+            bytes.push(height);
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_block_from_bytes() {
+        let block = Block::from_bytes(&serialized_block(&[[0xab; 32]], Some(9))).unwrap();
+        assert_eq!(
+            block.hash(),
+            BlockHeader::get_genesis(BlockchainId::Main).hash()
+        );
+        assert_eq!(block.transaction_count, 1);
+        assert_eq!(block.size_in_bytes, 285);
+        assert_eq!(block.subtree_hashes, vec![Hash::from([0xab; 32])]);
+        assert_eq!(block.coinbase_tx, Tx::from_hex(GENESIS_COINBASE).unwrap());
+        assert_eq!(block.height, 9);
+        assert_eq!(block.id, None);
+        assert_eq!(block.to_bytes(), serialized_block(&[[0xab; 32]], Some(9)));
+    }
+
+    #[test]
+    fn test_block_from_bytes_without_height() {
+        let block = Block::from_bytes(&serialized_block(&[], None)).unwrap();
+        assert!(block.subtree_hashes.is_empty());
+        assert_eq!(block.height, 0);
+    }
+
+    #[test]
+    fn test_block_from_bytes_truncated() {
+        let bytes = serialized_block(&[[0xab; 32]], None);
+        assert!(Block::from_bytes(&bytes[..90]).is_err());
+        assert!(Block::from_bytes(&bytes[..80]).is_err());
+    }
+
+    #[test]
+    fn test_block_from_response() {
+        let response = GetBlockResponse {
+            header: BlockHeader::get_genesis(BlockchainId::Main).raw.to_vec(),
+            height: 0,
+            coinbase_tx: Vec::<u8>::from_hex(GENESIS_COINBASE).unwrap(),
+            transaction_count: 1,
+            subtree_hashes: vec![vec![0x01; 32]],
+            size_in_bytes: 285,
+            id: 3,
+        };
+
+        let block = Block::try_from(response.clone()).unwrap();
+        assert_eq!(block.id, Some(3));
+        assert_eq!(block.subtree_hashes, vec![Hash::from([0x01; 32])]);
+
+        let bad = GetBlockResponse {
+            subtree_hashes: vec![vec![0x01; 31]],
+            ..response
+        };
+        assert!(Block::try_from(bad).is_err());
+    }
+}
@@ -0,0 +1,322 @@
+//! Builder for configuring a [`TeranodeClient`]
+
+use crate::client::TeranodeClient;
+use crate::error::{Result, TeranodeError};
+use crate::interceptor::{ClientInterceptor, InterceptorFn};
+use crate::retry::RetryPolicy;
+use crate::store::HeaderStore;
+use crate::tls::TlsConfig;
+use std::sync::Arc;
+use std::time::Duration;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Request, Status};
+
+/// Builder for a [`TeranodeClient`]
+///
+/// ```no_run
+/// # async fn example() -> teranode_client::Result<()> {
+/// use std::time::Duration;
+/// use teranode_client::TeranodeClient;
+///
+/// let client = TeranodeClient::builder()
+///     .blockchain_endpoint("http://127.0.0.1:8087")
+///     .connect_timeout(Duration::from_secs(5))
+///     .request_timeout(Duration::from_secs(30))
+///     .bearer_token("secret")
+///     .request_ids(true)
+///     .connect()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct TeranodeClientBuilder {
+    blockchain_endpoint: Option<String>,
+    peer_endpoint: Option<String>,
+    tls: Option<TlsConfig>,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    keep_alive_interval: Option<Duration>,
+    keep_alive_timeout: Option<Duration>,
+    keep_alive_while_idle: bool,
+    tcp_keepalive: Option<Duration>,
+    bearer_token: Option<String>,
+    headers: Vec<(String, String)>,
+    request_ids: bool,
+    interceptors: Vec<Arc<InterceptorFn>>,
+    retry_policy: RetryPolicy,
+    header_store: Option<HeaderStore>,
+}
+
+impl TeranodeClientBuilder {
+    /// Create a builder with no endpoints and default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the blockchain service endpoint (e.g., "http://127.0.0.1:8087")
+    pub fn blockchain_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.blockchain_endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Set the peer service endpoint (e.g., "http://127.0.0.1:8088")
+    pub fn peer_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.peer_endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Use custom TLS settings for `https://` endpoints
+    pub fn tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Fail connection attempts that take longer than `timeout`
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Deadline applied to each attempt of every unary RPC
+    ///
+    /// Queries that run out of time are retried according to the retry
+    /// policy, so a query may take up to `max_attempts` times this long,
+    /// plus backoff, before failing with [`TeranodeError::Timeout`].
+    ///
+    /// Calls that take an explicit timeout, such as the FSM methods, use that
+    /// instead. Notification subscriptions are never subject to a deadline.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Send HTTP/2 keep-alive pings every `interval`, closing the connection
+    /// if one isn't acknowledged within `timeout`
+    pub fn keep_alive(mut self, interval: Duration, timeout: Duration) -> Self {
+        self.keep_alive_interval = Some(interval);
+        self.keep_alive_timeout = Some(timeout);
+        self
+    }
+
+    /// Keep sending keep-alive pings while no requests are in flight
+    pub fn keep_alive_while_idle(mut self, enabled: bool) -> Self {
+        self.keep_alive_while_idle = enabled;
+        self
+    }
+
+    /// Enable TCP keep-alive on the underlying sockets
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Send `Authorization: Bearer <token>` with every request
+    pub fn bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
+    /// Send a metadata header with every request
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((key.into(), value.into()));
+        self
+    }
+
+    /// Tag every request with a unique `x-request-id` header
+    pub fn request_ids(mut self, enabled: bool) -> Self {
+        self.request_ids = enabled;
+        self
+    }
+
+    /// Append a custom interceptor, run after the built-in headers are added
+    pub fn interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(Request<()>) -> std::result::Result<Request<()>, Status> + Send + Sync + 'static,
+    {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Set the policy used to retry idempotent queries
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Serve [`get_stored_header`](TeranodeClient::get_stored_header) lookups
+    /// from `store`, adding fetched headers to it
+    pub fn header_store(mut self, store: HeaderStore) -> Self {
+        self.header_store = Some(store);
+        self
+    }
+
+    /// Connect to the configured endpoints
+    pub async fn connect(self) -> Result<TeranodeClient> {
+        let blockchain_channel = match &self.blockchain_endpoint {
+            Some(endpoint) => Some(self.endpoint(endpoint)?.connect().await?),
+            None => None,
+        };
+
+        let peer_channel = match &self.peer_endpoint {
+            Some(endpoint) => Some(self.endpoint(endpoint)?.connect().await?),
+            None => None,
+        };
+
+        self.build(blockchain_channel, peer_channel)
+    }
+
+    /// Create a client whose connections are established on first use
+    pub fn connect_lazy(self) -> Result<TeranodeClient> {
+        let blockchain_channel = self
+            .blockchain_endpoint
+            .as_deref()
+            .map(|endpoint| self.endpoint(endpoint).map(|e| e.connect_lazy()))
+            .transpose()?;
+
+        let peer_channel = self
+            .peer_endpoint
+            .as_deref()
+            .map(|endpoint| self.endpoint(endpoint).map(|e| e.connect_lazy()))
+            .transpose()?;
+
+        self.build(blockchain_channel, peer_channel)
+    }
+
+    /// Parse and configure an endpoint
+    fn endpoint(&self, endpoint: &str) -> Result<Endpoint> {
+        let mut endpoint = parse_endpoint(endpoint, self.tls.as_ref())?
+            .keep_alive_while_idle(self.keep_alive_while_idle)
+            .tcp_keepalive(self.tcp_keepalive);
+
+        if let Some(timeout) = self.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(interval) = self.keep_alive_interval {
+            endpoint = endpoint.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = self.keep_alive_timeout {
+            endpoint = endpoint.keep_alive_timeout(timeout);
+        }
+
+        Ok(endpoint)
+    }
+
+    fn build(
+        self,
+        blockchain_channel: Option<Channel>,
+        peer_channel: Option<Channel>,
+    ) -> Result<TeranodeClient> {
+        let mut interceptor = ClientInterceptor::new();
+        if let Some(token) = &self.bearer_token {
+            interceptor = interceptor.with_bearer_token(token)?;
+        }
+        for (key, value) in &self.headers {
+            interceptor = interceptor.with_header(key, value)?;
+        }
+        if self.request_ids {
+            interceptor = interceptor.with_request_ids();
+        }
+        for f in self.interceptors {
+            interceptor = interceptor.push(f);
+        }
+
+        Ok(TeranodeClient::from_parts(
+            blockchain_channel,
+            peer_channel,
+            interceptor,
+            self.retry_policy,
+            self.request_timeout,
+            self.header_store,
+        ))
+    }
+}
+
+/// Parse an endpoint URL, enabling TLS for `https://` endpoints
+fn parse_endpoint(endpoint: &str, tls: Option<&TlsConfig>) -> Result<Endpoint> {
+    let parsed = Channel::from_shared(endpoint.to_string())
+        .map_err(|e| TeranodeError::ConfigError(format!("Invalid endpoint URL: {}", e)))?;
+
+    if parsed.uri().scheme_str() != Some("https") {
+        return match tls {
+            Some(_) => Err(TeranodeError::ConfigError(format!(
+                "TLS is configured but endpoint {} is not https",
+                endpoint
+            ))),
+            None => Ok(parsed),
+        };
+    }
+
+    let tls_config = match tls {
+        Some(tls) => tls.client_tls_config()?,
+        None => TlsConfig::default().client_tls_config()?,
+    };
+    Ok(parsed.tls_config(tls_config)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockTeranode;
+
+    #[tokio::test]
+    async fn test_builder_validation() {
+        let client = TeranodeClientBuilder::new()
+            .blockchain_endpoint("http://127.0.0.1:8087")
+            .request_timeout(Duration::from_secs(1))
+            .bearer_token("secret")
+            .connect_lazy();
+        assert!(client.is_ok());
+
+        let bad_header = TeranodeClientBuilder::new()
+            .blockchain_endpoint("http://127.0.0.1:8087")
+            .header("bad key", "value")
+            .connect_lazy();
+        assert!(matches!(bad_header, Err(TeranodeError::ConfigError(_))));
+
+        let bad_token = TeranodeClientBuilder::new()
+            .blockchain_endpoint("http://127.0.0.1:8087")
+            .bearer_token("bad\ntoken")
+            .connect_lazy();
+        assert!(matches!(bad_token, Err(TeranodeError::ConfigError(_))));
+
+        let plaintext_tls = TeranodeClientBuilder::new()
+            .blockchain_endpoint("http://127.0.0.1:8087")
+            .tls(TlsConfig::default())
+            .connect_lazy();
+        assert!(matches!(plaintext_tls, Err(TeranodeError::ConfigError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_against_mock() {
+        let mock = MockTeranode::new();
+        let server = mock.serve().await.unwrap();
+        let timeout = Duration::from_millis(100);
+        let mut client = TeranodeClientBuilder::new()
+            .blockchain_endpoint(server.endpoint())
+            .request_timeout(timeout)
+            .retry_policy(RetryPolicy {
+                initial_backoff: Duration::from_millis(1),
+                ..RetryPolicy::default()
+            })
+            .connect()
+            .await
+            .unwrap();
+
+        // A hung first attempt is abandoned and retried
+        mock.delay_next(Duration::from_secs(5));
+        assert_eq!(client.get_best_block_header().await.unwrap().height, 0);
+
+        for _ in 0..3 {
+            mock.delay_next(Duration::from_secs(5));
+        }
+        let err = client.get_best_block_header().await.unwrap_err();
+        assert!(matches!(err, TeranodeError::Timeout(t) if t == timeout));
+
+        // Mutating calls are never retried
+        mock.delay_next(Duration::from_secs(5));
+        let err = client.set_state("key", vec![1]).await.unwrap_err();
+        assert!(matches!(err, TeranodeError::Timeout(_)));
+        client.set_state("key", vec![1]).await.unwrap();
+    }
+}
@@ -0,0 +1,1825 @@
+//! High-level client interface for Teranode
+
+use crate::backlog::{Backlog, PendingBlocks};
+use crate::block::{decode_blocks, Block};
+use crate::builder::TeranodeClientBuilder;
+use crate::error::{Result, TeranodeError};
+use crate::fork::{ChainTip, Fork};
+use crate::fsm::{FsmEvent, FsmState};
+use crate::header::{
+    decode_block_hash, decode_header, decode_headers_with_metas, hash_bytes, HeaderWithMeta,
+};
+use crate::health::Health;
+use crate::interceptor::{ClientInterceptor, Transport};
+use crate::locator::BestHeightAndTime;
+use crate::mining::{decode_candidate, CandidateCheck, SuitableBlock};
+use crate::notification::{subscription_stream, ReconnectBackoff, SubscriptionEvent};
+use crate::paging::{height_bounds, paged_stream, PageOptions};
+use crate::peer::{validate_peer_multiaddr, IpSubnet};
+use crate::proto::blockchain_api::{
+    blockchain_api_client::BlockchainApiClient, CheckBlockIsCurrentChainRequest,
+    FindBlocksContainingSubtreeRequest, FsmEventType, FsmStateType, GetBlockByHeightRequest,
+    GetBlockByIdRequest, GetBlockGraphDataRequest, GetBlockHeaderRequest, GetBlockHeaderResponse,
+    GetBlockHeadersByHeightRequest, GetBlockHeadersFromCommonAncestorRequest,
+    GetBlockHeadersFromHeightRequest, GetBlockHeadersFromOldestRequest,
+    GetBlockHeadersFromTillRequest, GetBlockHeadersRequest, GetBlockHeadersToCommonAncestorRequest,
+    GetBlockIsMinedRequest, GetBlockLocatorRequest, GetBlockRequest, GetBlocksByHeightRequest,
+    GetBlocksRequest, GetHashOfAncestorBlockRequest, GetLastNBlocksRequest,
+    GetLastNInvalidBlocksRequest, GetLatestBlockHeaderFromBlockLocatorRequest,
+    GetNextWorkRequiredRequest, GetStateRequest, GetSuitableBlockRequest, InvalidateBlockRequest,
+    LocateBlockHeadersRequest, RevalidateBlockRequest, SendFsmEventRequest,
+    SetBlockMinedSetRequest, SetBlockProcessedAtRequest, SetBlockSubtreesSetRequest,
+    SetStateRequest, WaitFsmToTransitionRequest,
+};
+use crate::proto::model::MiningCandidate;
+use crate::proto::p2p_api::{
+    peer_service_client::PeerServiceClient, AddBanScoreRequest, BanPeerRequest, ConnectPeerRequest,
+    DisconnectPeerRequest, GetPeersResponse, IsBannedRequest, UnbanPeerRequest,
+};
+use crate::retry::RetryPolicy;
+use crate::stats::{BlockInfo, BlockStats, DataPoint};
+use crate::store::{HeaderStore, StoredHeader};
+use crate::tls::TlsConfig;
+use crate::work::Target;
+use bitcoinsv::bitcoin::{BlockHash, BlockHeader, Hash};
+use futures::Stream;
+use libp2p::{Multiaddr, PeerId};
+use std::collections::HashSet;
+use std::future::Future;
+use std::ops::RangeBounds;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tonic::transport::Channel;
+use tonic::{Code, Request, Response, Status};
+
+/// Main client for interacting with Teranode
+///
+/// Cloning is cheap; clones share the underlying connections.
+#[derive(Clone)]
+pub struct TeranodeClient {
+    blockchain_client: Option<BlockchainApiClient<Transport>>,
+    peer_client: Option<PeerServiceClient<Transport>>,
+    retry_policy: RetryPolicy,
+    request_timeout: Option<Duration>,
+    header_store: Option<HeaderStore>,
+}
+
+impl TeranodeClient {
+    /// Create a builder for configuring timeouts, TLS and interceptors
+    pub fn builder() -> TeranodeClientBuilder {
+        TeranodeClientBuilder::new()
+    }
+
+    /// Create a new Teranode client with blockchain endpoint
+    ///
+    /// # Arguments
+    /// * `endpoint` - The gRPC endpoint (e.g., "http://127.0.0.1:8087")
+    pub async fn connect(endpoint: impl AsRef<str>) -> Result<Self> {
+        Self::builder()
+            .blockchain_endpoint(endpoint.as_ref())
+            .connect()
+            .await
+    }
+
+    /// Create a new Teranode client with both blockchain and peer endpoints
+    ///
+    /// `https://` endpoints are verified against the system root certificates;
+    /// use [`connect_with_tls`](Self::connect_with_tls) for custom CAs or mTLS.
+    ///
+    /// # Arguments
+    /// * `blockchain_endpoint` - The blockchain service gRPC endpoint
+    /// * `peer_endpoint` - The peer service gRPC endpoint
+    pub async fn connect_with_endpoints(
+        blockchain_endpoint: Option<impl AsRef<str>>,
+        peer_endpoint: Option<impl AsRef<str>>,
+    ) -> Result<Self> {
+        Self::builder_for(blockchain_endpoint, peer_endpoint)
+            .connect()
+            .await
+    }
+
+    /// Create a new Teranode client for `https://` endpoints with custom TLS settings
+    ///
+    /// # Arguments
+    /// * `blockchain_endpoint` - The blockchain service gRPC endpoint
+    /// * `peer_endpoint` - The peer service gRPC endpoint
+    /// * `tls` - CA bundle, client identity and SNI override to use
+    pub async fn connect_with_tls(
+        blockchain_endpoint: Option<impl AsRef<str>>,
+        peer_endpoint: Option<impl AsRef<str>>,
+        tls: &TlsConfig,
+    ) -> Result<Self> {
+        Self::builder_for(blockchain_endpoint, peer_endpoint)
+            .tls(tls.clone())
+            .connect()
+            .await
+    }
+
+    /// Create a client whose connections are established on first use
+    ///
+    /// Unlike [`connect_with_endpoints`](Self::connect_with_endpoints) this
+    /// succeeds even if the node is down. The underlying channels reconnect
+    /// by themselves whenever the connection drops, so a client created this
+    /// way keeps working across node restarts.
+    ///
+    /// # Arguments
+    /// * `blockchain_endpoint` - The blockchain service gRPC endpoint
+    /// * `peer_endpoint` - The peer service gRPC endpoint
+    pub fn connect_lazy(
+        blockchain_endpoint: Option<impl AsRef<str>>,
+        peer_endpoint: Option<impl AsRef<str>>,
+    ) -> Result<Self> {
+        Self::builder_for(blockchain_endpoint, peer_endpoint).connect_lazy()
+    }
+
+    /// Set the policy used to retry idempotent queries
+    ///
+    /// Defaults to [`RetryPolicy::default`]. Use [`RetryPolicy::none`] to
+    /// disable retries.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    fn builder_for(
+        blockchain_endpoint: Option<impl AsRef<str>>,
+        peer_endpoint: Option<impl AsRef<str>>,
+    ) -> TeranodeClientBuilder {
+        let mut builder = Self::builder();
+        if let Some(endpoint) = blockchain_endpoint {
+            builder = builder.blockchain_endpoint(endpoint.as_ref());
+        }
+        if let Some(endpoint) = peer_endpoint {
+            builder = builder.peer_endpoint(endpoint.as_ref());
+        }
+        builder
+    }
+
+    pub(crate) fn from_parts(
+        blockchain_channel: Option<Channel>,
+        peer_channel: Option<Channel>,
+        interceptor: ClientInterceptor,
+        retry_policy: RetryPolicy,
+        request_timeout: Option<Duration>,
+        header_store: Option<HeaderStore>,
+    ) -> Self {
+        Self {
+            blockchain_client: blockchain_channel
+                .map(|channel| BlockchainApiClient::with_interceptor(channel, interceptor.clone())),
+            peer_client: peer_channel
+                .map(|channel| PeerServiceClient::with_interceptor(channel, interceptor)),
+            retry_policy,
+            request_timeout,
+            header_store,
+        }
+    }
+
+    /// Get the blockchain service client, failing if it was not configured
+    fn blockchain_client(&mut self) -> Result<&mut BlockchainApiClient<Transport>> {
+        self.blockchain_client
+            .as_mut()
+            .ok_or(TeranodeError::ServiceNotConfigured("Blockchain"))
+    }
+
+    /// Get the peer service client, failing if it was not configured
+    fn peer_client(&mut self) -> Result<&mut PeerServiceClient<Transport>> {
+        self.peer_client
+            .as_mut()
+            .ok_or(TeranodeError::ServiceNotConfigured("Peer"))
+    }
+
+    /// Run an idempotent blockchain query, retrying according to the retry policy
+    async fn query_blockchain<R, T, F, Fut>(&mut self, request: R, call: F) -> Result<T>
+    where
+        R: Clone,
+        F: Fn(BlockchainApiClient<Transport>, Request<R>) -> Fut,
+        Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+    {
+        let timeout = self.request_timeout;
+        self.query_blockchain_within(timeout, request, call).await
+    }
+
+    /// Run an idempotent blockchain query with its own per-attempt deadline
+    async fn query_blockchain_within<R, T, F, Fut>(
+        &mut self,
+        timeout: Option<Duration>,
+        request: R,
+        call: F,
+    ) -> Result<T>
+    where
+        R: Clone,
+        F: Fn(BlockchainApiClient<Transport>, Request<R>) -> Fut,
+        Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+    {
+        let client = self.blockchain_client()?.clone();
+        let policy = self.retry_policy.clone();
+        execute(client, request, call, &policy, timeout).await
+    }
+
+    /// Send a state-changing blockchain request, which is never retried
+    async fn send_blockchain<R, T, F, Fut>(&mut self, request: R, call: F) -> Result<T>
+    where
+        R: Clone,
+        F: Fn(BlockchainApiClient<Transport>, Request<R>) -> Fut,
+        Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+    {
+        let timeout = self.request_timeout;
+        self.send_blockchain_within(timeout, request, call).await
+    }
+
+    /// Send a state-changing blockchain request with its own deadline
+    async fn send_blockchain_within<R, T, F, Fut>(
+        &mut self,
+        timeout: Option<Duration>,
+        request: R,
+        call: F,
+    ) -> Result<T>
+    where
+        R: Clone,
+        F: Fn(BlockchainApiClient<Transport>, Request<R>) -> Fut,
+        Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+    {
+        let client = self.blockchain_client()?.clone();
+        execute(client, request, call, &RetryPolicy::none(), timeout).await
+    }
+
+    /// Run an idempotent peer service query, retrying according to the retry policy
+    async fn query_peer<R, T, F, Fut>(&mut self, request: R, call: F) -> Result<T>
+    where
+        R: Clone,
+        F: Fn(PeerServiceClient<Transport>, Request<R>) -> Fut,
+        Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+    {
+        let client = self.peer_client()?.clone();
+        let policy = self.retry_policy.clone();
+        execute(client, request, call, &policy, self.request_timeout).await
+    }
+
+    /// Send a state-changing peer service request, which is never retried
+    async fn send_peer<R, T, F, Fut>(&mut self, request: R, call: F) -> Result<T>
+    where
+        R: Clone,
+        F: Fn(PeerServiceClient<Transport>, Request<R>) -> Fut,
+        Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+    {
+        let client = self.peer_client()?.clone();
+        execute(
+            client,
+            request,
+            call,
+            &RetryPolicy::none(),
+            self.request_timeout,
+        )
+        .await
+    }
+
+    /// Check the health of the blockchain service with HealthGRPC
+    ///
+    /// An unhealthy service still answers, with `ok` unset and the failing
+    /// dependencies in the details.
+    pub async fn health(&mut self) -> Result<Health> {
+        let response = self
+            .query_blockchain((), |mut client, request| async move {
+                client.health_grpc(request).await
+            })
+            .await?;
+
+        Ok(Health::from(response))
+    }
+
+    /// Get the best (tip) block header
+    ///
+    /// # Returns
+    /// The header of the current best block in the blockchain
+    pub async fn get_best_block_header(&mut self) -> Result<GetBlockHeaderResponse> {
+        self.query_blockchain((), |mut client, request| async move {
+            client.get_best_block_header(request).await
+        })
+        .await
+    }
+
+    /// Get the header of a specific block
+    ///
+    /// Always asks the node, since flags such as `invalid` change over time.
+    /// The immutable parts are added to the header store, if the client has
+    /// one; see [`get_stored_header`](Self::get_stored_header).
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block
+    pub async fn get_block_header(&mut self, hash: &BlockHash) -> Result<HeaderWithMeta> {
+        let header = self.fetch_block_header(hash).await?;
+        if let Some(store) = &self.header_store {
+            // The block may be on a fork, so don't index it by height
+            store.insert(&StoredHeader::try_from(&header)?)?;
+        }
+        Ok(header)
+    }
+
+    /// Get the header at a height on the current chain
+    ///
+    /// Always asks the node, and adds the immutable parts to the header
+    /// store, if the client has one; see
+    /// [`get_stored_header_by_height`](Self::get_stored_header_by_height).
+    ///
+    /// # Arguments
+    /// * `height` - Height of the block
+    pub async fn get_block_header_by_height(&mut self, height: u32) -> Result<HeaderWithMeta> {
+        let header = self
+            .get_block_headers_by_height(height, height)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| TeranodeError::NotFound(format!("No block at height {}", height)))?;
+        if let Some(store) = &self.header_store {
+            store.insert_on_chain(&StoredHeader::try_from(&header)?)?;
+        }
+        Ok(header)
+    }
+
+    /// Get the header, height and chain work of a block
+    ///
+    /// Served from the header store when the client has one and the block
+    /// is stored, otherwise fetched from the node and stored.
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block
+    pub async fn get_stored_header(&mut self, hash: &BlockHash) -> Result<StoredHeader> {
+        if let Some(header) = self.stored_header(|store| store.get(hash))? {
+            return Ok(header);
+        }
+        StoredHeader::try_from(&self.get_block_header(hash).await?)
+    }
+
+    /// Get the header, height and chain work of the block at a height on the
+    /// current chain
+    ///
+    /// Served from the header store when the client has one and the height
+    /// is stored, otherwise fetched from the node and stored. Stored heights
+    /// only follow reorgs while [`HeaderStore::follow`] is running.
+    ///
+    /// # Arguments
+    /// * `height` - Height of the block
+    pub async fn get_stored_header_by_height(&mut self, height: u32) -> Result<StoredHeader> {
+        if let Some(header) = self.stored_header(|store| store.get_by_height(height))? {
+            return Ok(header);
+        }
+        StoredHeader::try_from(&self.get_block_header_by_height(height).await?)
+    }
+
+    /// Get a block header from the blockchain service, bypassing the header store
+    pub(crate) async fn fetch_block_header(&mut self, hash: &BlockHash) -> Result<HeaderWithMeta> {
+        let response = self
+            .query_blockchain(
+                GetBlockHeaderRequest {
+                    block_hash: hash_bytes(hash),
+                },
+                |mut client, request| async move { client.get_block_header(request).await },
+            )
+            .await?;
+
+        HeaderWithMeta::try_from(response)
+    }
+
+    fn stored_header(
+        &self,
+        lookup: impl FnOnce(&HeaderStore) -> Result<Option<StoredHeader>>,
+    ) -> Result<Option<StoredHeader>> {
+        match &self.header_store {
+            Some(store) => lookup(store),
+            None => Ok(None),
+        }
+    }
+
+    /// Get up to `count` headers, walking back from `start_hash`
+    ///
+    /// # Arguments
+    /// * `start_hash` - Hash of the block to start from
+    /// * `count` - Maximum number of headers to return
+    pub async fn get_block_headers(
+        &mut self,
+        start_hash: &BlockHash,
+        count: u64,
+    ) -> Result<Vec<HeaderWithMeta>> {
+        let response = self
+            .query_blockchain(
+                GetBlockHeadersRequest {
+                    start_hash: hash_bytes(start_hash),
+                    number_of_headers: count,
+                },
+                |mut client, request| async move { client.get_block_headers(request).await },
+            )
+            .await?;
+
+        decode_headers_with_metas(&response.block_headers, &response.metas)
+    }
+
+    /// Get the headers between two blocks
+    ///
+    /// # Arguments
+    /// * `start_hash` - Hash of the first block
+    /// * `end_hash` - Hash of the last block
+    pub async fn get_block_headers_from_till(
+        &mut self,
+        start_hash: &BlockHash,
+        end_hash: &BlockHash,
+    ) -> Result<Vec<HeaderWithMeta>> {
+        let response = self
+            .query_blockchain(
+                GetBlockHeadersFromTillRequest {
+                    start_hash: hash_bytes(start_hash),
+                    end_hash: hash_bytes(end_hash),
+                },
+                |mut client, request| async move {
+                    client.get_block_headers_from_till(request).await
+                },
+            )
+            .await?;
+
+        decode_headers_with_metas(&response.block_headers, &response.metas)
+    }
+
+    /// Get up to `limit` headers starting at `start_height`
+    ///
+    /// # Arguments
+    /// * `start_height` - Height of the first header
+    /// * `limit` - Maximum number of headers to return
+    pub async fn get_block_headers_from_height(
+        &mut self,
+        start_height: u32,
+        limit: u32,
+    ) -> Result<Vec<HeaderWithMeta>> {
+        let response = self
+            .query_blockchain(
+                GetBlockHeadersFromHeightRequest {
+                    start_height,
+                    limit,
+                },
+                |mut client, request| async move {
+                    client.get_block_headers_from_height(request).await
+                },
+            )
+            .await?;
+
+        decode_headers_with_metas(&response.block_headers, &response.metas)
+    }
+
+    /// Get the headers between two heights
+    ///
+    /// # Arguments
+    /// * `start_height` - Height of the first header
+    /// * `end_height` - Height of the last header
+    pub async fn get_block_headers_by_height(
+        &mut self,
+        start_height: u32,
+        end_height: u32,
+    ) -> Result<Vec<HeaderWithMeta>> {
+        let response = self
+            .query_blockchain(
+                GetBlockHeadersByHeightRequest {
+                    start_height,
+                    end_height,
+                },
+                |mut client, request| async move {
+                    client.get_block_headers_by_height(request).await
+                },
+            )
+            .await?;
+
+        decode_headers_with_metas(&response.block_headers, &response.metas)
+    }
+
+    /// Get headers starting from the oldest block on the path to `target_hash`
+    ///
+    /// # Arguments
+    /// * `chain_tip_hash` - Hash of the chain tip defining the chain to follow
+    /// * `target_hash` - Hash of the target block
+    /// * `count` - Maximum number of headers to return
+    pub async fn get_block_headers_from_oldest(
+        &mut self,
+        chain_tip_hash: &BlockHash,
+        target_hash: &BlockHash,
+        count: u64,
+    ) -> Result<Vec<HeaderWithMeta>> {
+        let response = self
+            .query_blockchain(
+                GetBlockHeadersFromOldestRequest {
+                    chain_tip_hash: hash_bytes(chain_tip_hash),
+                    target_hash: hash_bytes(target_hash),
+                    number_of_headers: count,
+                },
+                |mut client, request| async move {
+                    client.get_block_headers_from_oldest(request).await
+                },
+            )
+            .await?;
+
+        decode_headers_with_metas(&response.block_headers, &response.metas)
+    }
+
+    /// Get the headers from `target_hash` back to its common ancestor with the locator
+    ///
+    /// # Arguments
+    /// * `target_hash` - Hash of the target block
+    /// * `locator` - Block locator hashes describing the caller's chain
+    /// * `max_headers` - Maximum number of headers to return
+    pub async fn get_block_headers_to_common_ancestor(
+        &mut self,
+        target_hash: &BlockHash,
+        locator: &[BlockHash],
+        max_headers: u32,
+    ) -> Result<Vec<HeaderWithMeta>> {
+        let response = self
+            .query_blockchain(
+                GetBlockHeadersToCommonAncestorRequest {
+                    target_hash: hash_bytes(target_hash),
+                    block_locator_hashes: locator.iter().map(hash_bytes).collect(),
+                    max_headers,
+                },
+                |mut client, request| async move {
+                    client.get_block_headers_to_common_ancestor(request).await
+                },
+            )
+            .await?;
+
+        decode_headers_with_metas(&response.block_headers, &response.metas)
+    }
+
+    /// Get the headers from the common ancestor with the locator up to `target_hash`
+    ///
+    /// # Arguments
+    /// * `target_hash` - Hash of the target block
+    /// * `locator` - Block locator hashes describing the caller's chain
+    /// * `max_headers` - Maximum number of headers to return
+    pub async fn get_block_headers_from_common_ancestor(
+        &mut self,
+        target_hash: &BlockHash,
+        locator: &[BlockHash],
+        max_headers: u32,
+    ) -> Result<Vec<HeaderWithMeta>> {
+        let response = self
+            .query_blockchain(
+                GetBlockHeadersFromCommonAncestorRequest {
+                    target_hash: hash_bytes(target_hash),
+                    block_locator_hashes: locator.iter().map(hash_bytes).collect(),
+                    max_headers,
+                },
+                |mut client, request| async move {
+                    client.get_block_headers_from_common_ancestor(request).await
+                },
+            )
+            .await?;
+
+        decode_headers_with_metas(&response.block_headers, &response.metas)
+    }
+
+    /// Get a block by its hash
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block
+    pub async fn get_block(&mut self, hash: &BlockHash) -> Result<Block> {
+        let response = self
+            .query_blockchain(
+                GetBlockRequest {
+                    hash: hash_bytes(hash),
+                },
+                |mut client, request| async move { client.get_block(request).await },
+            )
+            .await?;
+
+        Block::try_from(response)
+    }
+
+    /// Get up to `count` blocks starting from `start_hash`
+    ///
+    /// # Arguments
+    /// * `start_hash` - Hash of the first block
+    /// * `count` - Maximum number of blocks to return
+    pub async fn get_blocks(&mut self, start_hash: &BlockHash, count: u32) -> Result<Vec<Block>> {
+        let response = self
+            .query_blockchain(
+                GetBlocksRequest {
+                    hash: hash_bytes(start_hash),
+                    count,
+                },
+                |mut client, request| async move { client.get_blocks(request).await },
+            )
+            .await?;
+
+        decode_blocks(&response.blocks)
+    }
+
+    /// Get the block at a specific height on the main chain
+    ///
+    /// # Arguments
+    /// * `height` - Block height
+    pub async fn get_block_by_height(&mut self, height: u32) -> Result<Block> {
+        let response = self
+            .query_blockchain(
+                GetBlockByHeightRequest { height },
+                |mut client, request| async move { client.get_block_by_height(request).await },
+            )
+            .await?;
+
+        Block::try_from(response)
+    }
+
+    /// Get a block by its internal Teranode identifier
+    ///
+    /// # Arguments
+    /// * `id` - Block identifier
+    pub async fn get_block_by_id(&mut self, id: u64) -> Result<Block> {
+        let response = self
+            .query_blockchain(
+                GetBlockByIdRequest { id },
+                |mut client, request| async move { client.get_block_by_id(request).await },
+            )
+            .await?;
+
+        Block::try_from(response)
+    }
+
+    /// Get the blocks between two heights
+    ///
+    /// # Arguments
+    /// * `start_height` - Height of the first block
+    /// * `end_height` - Height of the last block
+    pub async fn get_blocks_by_height(
+        &mut self,
+        start_height: u32,
+        end_height: u32,
+    ) -> Result<Vec<Block>> {
+        let response = self
+            .query_blockchain(
+                GetBlocksByHeightRequest {
+                    start_height,
+                    end_height,
+                },
+                |mut client, request| async move { client.get_blocks_by_height(request).await },
+            )
+            .await?;
+
+        decode_blocks(&response.blocks)
+    }
+
+    /// Stream the headers in a height range, in height order
+    ///
+    /// The range is fetched in batches with [`PageOptions::headers`]. An
+    /// open-ended range such as `100..` streams up to the current tip.
+    ///
+    /// # Arguments
+    /// * `range` - Heights of the headers to stream
+    pub fn headers_stream(
+        &self,
+        range: impl RangeBounds<u32>,
+    ) -> impl Stream<Item = Result<HeaderWithMeta>> + Send + 'static {
+        self.headers_stream_with_options(range, PageOptions::headers())
+    }
+
+    /// Stream the headers in a height range with custom batching
+    ///
+    /// # Arguments
+    /// * `range` - Heights of the headers to stream
+    /// * `options` - Batch size and number of concurrent requests
+    pub fn headers_stream_with_options(
+        &self,
+        range: impl RangeBounds<u32>,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<HeaderWithMeta>> + Send + 'static {
+        let client = self.clone();
+        paged_stream(height_bounds(range), options, move |first, last| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .get_block_headers_from_height(first, last - first + 1)
+                    .await
+            }
+        })
+    }
+
+    /// Stream the blocks in a height range, in height order
+    ///
+    /// The range is fetched in batches with [`PageOptions::blocks`]. An
+    /// open-ended range such as `100..` streams up to the current tip.
+    ///
+    /// # Arguments
+    /// * `range` - Heights of the blocks to stream
+    pub fn blocks_stream(
+        &self,
+        range: impl RangeBounds<u32>,
+    ) -> impl Stream<Item = Result<Block>> + Send + 'static {
+        self.blocks_stream_with_options(range, PageOptions::blocks())
+    }
+
+    /// Stream the blocks in a height range with custom batching
+    ///
+    /// # Arguments
+    /// * `range` - Heights of the blocks to stream
+    /// * `options` - Batch size and number of concurrent requests
+    pub fn blocks_stream_with_options(
+        &self,
+        range: impl RangeBounds<u32>,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<Block>> + Send + 'static {
+        let client = self.clone();
+        paged_stream(height_bounds(range), options, move |first, last| {
+            let mut client = client.clone();
+            async move { client.get_blocks_by_height(first, last).await }
+        })
+    }
+
+    /// Find the blocks that contain a subtree
+    ///
+    /// # Arguments
+    /// * `subtree_hash` - Hash of the subtree
+    /// * `max_blocks` - Maximum number of blocks to return, 0 for no limit
+    pub async fn find_blocks_containing_subtree(
+        &mut self,
+        subtree_hash: &Hash,
+        max_blocks: u32,
+    ) -> Result<Vec<Block>> {
+        let response = self
+            .query_blockchain(
+                FindBlocksContainingSubtreeRequest {
+                    subtree_hash: hash_bytes(subtree_hash),
+                    max_blocks,
+                },
+                |mut client, request| async move {
+                    client.find_blocks_containing_subtree(request).await
+                },
+            )
+            .await?;
+
+        decode_blocks(&response.blocks)
+    }
+
+    /// Get all known tips of the block tree, including the active tip
+    pub async fn get_chain_tips(&mut self) -> Result<Vec<ChainTip>> {
+        let response = self
+            .query_blockchain((), |mut client, request| async move {
+                client.get_chain_tips(request).await
+            })
+            .await?;
+
+        response.tips.into_iter().map(ChainTip::try_from).collect()
+    }
+
+    /// Check whether all of the given blocks are on the active chain
+    ///
+    /// # Arguments
+    /// * `block_ids` - Internal block IDs, as found in [`BlockHeaderMeta::id`](crate::BlockHeaderMeta::id)
+    pub async fn check_block_is_in_current_chain(&mut self, block_ids: &[u32]) -> Result<bool> {
+        let response = self
+            .query_blockchain(
+                CheckBlockIsCurrentChainRequest {
+                    block_i_ds: block_ids.to_vec(),
+                },
+                |mut client, request| async move {
+                    client.check_block_is_in_current_chain(request).await
+                },
+            )
+            .await?;
+
+        Ok(response.is_part_of_current_chain)
+    }
+
+    /// Get the hash of the block `depth` generations before `hash`
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block to start from
+    /// * `depth` - Number of generations to go back
+    pub async fn get_hash_of_ancestor_block(
+        &mut self,
+        hash: &BlockHash,
+        depth: u32,
+    ) -> Result<BlockHash> {
+        let response = self
+            .query_blockchain(
+                GetHashOfAncestorBlockRequest {
+                    hash: hash_bytes(hash),
+                    depth,
+                },
+                |mut client, request| async move {
+                    client.get_hash_of_ancestor_block(request).await
+                },
+            )
+            .await?;
+
+        decode_block_hash(&response.hash)
+    }
+
+    /// Get the block used as the reference point for difficulty adjustment
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block to start from
+    pub async fn get_suitable_block(&mut self, hash: &BlockHash) -> Result<SuitableBlock> {
+        let response = self
+            .query_blockchain(
+                GetSuitableBlockRequest {
+                    hash: hash_bytes(hash),
+                },
+                |mut client, request| async move { client.get_suitable_block(request).await },
+            )
+            .await?;
+
+        let block = response.block.ok_or_else(|| {
+            TeranodeError::DecodeError("GetSuitableBlock returned no block".to_string())
+        })?;
+        SuitableBlock::try_from(block)
+    }
+
+    /// Get the proof-of-work target required for the block after `previous_hash`
+    ///
+    /// # Arguments
+    /// * `previous_hash` - Hash of the block the next block builds on
+    /// * `block_time` - Timestamp of the next block, only used by the emergency
+    ///   difficulty rules of test networks
+    pub async fn get_next_work_required(
+        &mut self,
+        previous_hash: &BlockHash,
+        block_time: u32,
+    ) -> Result<Target> {
+        let response = self
+            .query_blockchain(
+                GetNextWorkRequiredRequest {
+                    previous_block_hash: hash_bytes(previous_hash),
+                    current_block_time: block_time as i64,
+                },
+                |mut client, request| async move { client.get_next_work_required(request).await },
+            )
+            .await?;
+
+        Target::try_from(&response)
+    }
+
+    /// Check a mining candidate's `nBits` against the node's next-work-required value
+    ///
+    /// # Arguments
+    /// * `candidate` - The candidate, as produced by the block assembly service
+    pub async fn check_mining_candidate(
+        &mut self,
+        candidate: &MiningCandidate,
+    ) -> Result<CandidateCheck> {
+        let (previous_hash, target) = decode_candidate(candidate)?;
+        let required = self
+            .get_next_work_required(&previous_hash, candidate.time)
+            .await?;
+
+        Ok(CandidateCheck {
+            previous_hash,
+            candidate: target,
+            required,
+        })
+    }
+
+    /// Describe every branch that competes with the active chain
+    pub async fn get_forks(&mut self) -> Result<Vec<Fork>> {
+        let mut forks = Vec::new();
+        for tip in self.get_chain_tips().await? {
+            if !tip.is_active() {
+                forks.push(self.analyze_fork(tip).await?);
+            }
+        }
+        Ok(forks)
+    }
+
+    /// Find where a branch leaves the active chain and collect its headers
+    ///
+    /// Starts from the tip's reported branch length and walks further back
+    /// with GetHashOfAncestorBlock until it reaches a block on the active chain.
+    ///
+    /// # Arguments
+    /// * `tip` - Tip of the branch, as returned by [`get_chain_tips`](Self::get_chain_tips)
+    pub async fn analyze_fork(&mut self, tip: ChainTip) -> Result<Fork> {
+        let mut depth = tip.branch_len;
+        let fork_point = loop {
+            let hash = self.get_hash_of_ancestor_block(&tip.hash, depth).await?;
+            let header = self.get_block_header(&hash).await?;
+            if self
+                .check_block_is_in_current_chain(&[header.meta.id])
+                .await?
+            {
+                break header;
+            }
+            if depth >= tip.height {
+                return Err(TeranodeError::NotFound(format!(
+                    "Fork point of branch {}",
+                    tip.hash
+                )));
+            }
+            depth += 1;
+        };
+
+        let mut branch = if depth > 0 {
+            self.get_block_headers(&tip.hash, depth as u64).await?
+        } else {
+            Vec::new()
+        };
+        branch.reverse();
+
+        let fork_height = fork_point.meta.height;
+        let competing = if depth > 0 {
+            self.get_block_headers_by_height(fork_height + 1, fork_height + depth)
+                .await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Fork {
+            tip,
+            fork_point,
+            branch,
+            competing,
+        })
+    }
+
+    /// Get the node's block locator for a block
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block the locator starts from
+    /// * `height` - Height of that block
+    pub async fn get_block_locator(
+        &mut self,
+        hash: &BlockHash,
+        height: u32,
+    ) -> Result<Vec<BlockHash>> {
+        let response = self
+            .query_blockchain(
+                GetBlockLocatorRequest {
+                    hash: hash_bytes(hash),
+                    height,
+                },
+                |mut client, request| async move { client.get_block_locator(request).await },
+            )
+            .await?;
+
+        response
+            .locator
+            .iter()
+            .map(|hash| decode_block_hash(hash))
+            .collect()
+    }
+
+    /// Get the headers following the most recent block the node shares with a locator
+    ///
+    /// # Arguments
+    /// * `locator` - Block locator, tip first, as built by [`HeaderStore::locator`]
+    /// * `hash_stop` - Hash of the last header wanted, or `None` to go up to `max_headers`
+    /// * `max_headers` - Maximum number of headers to return
+    pub async fn locate_block_headers(
+        &mut self,
+        locator: &[BlockHash],
+        hash_stop: Option<&BlockHash>,
+        max_headers: u32,
+    ) -> Result<Vec<BlockHeader>> {
+        let response = self
+            .query_blockchain(
+                LocateBlockHeadersRequest {
+                    locator: locator.iter().map(hash_bytes).collect(),
+                    hash_stop: hash_bytes(hash_stop.unwrap_or(&Hash::ZERO)),
+                    max_hashes: max_headers,
+                },
+                |mut client, request| async move { client.locate_block_headers(request).await },
+            )
+            .await?;
+
+        response
+            .block_headers
+            .iter()
+            .map(|header| decode_header(header))
+            .collect()
+    }
+
+    /// Get the most recent block from a locator that is on the chain ending at `best_hash`
+    ///
+    /// # Arguments
+    /// * `best_hash` - Tip of the chain to search
+    /// * `locator` - Block locator, tip first
+    pub async fn get_latest_block_header_from_block_locator(
+        &mut self,
+        best_hash: &BlockHash,
+        locator: &[BlockHash],
+    ) -> Result<HeaderWithMeta> {
+        let response = self
+            .query_blockchain(
+                GetLatestBlockHeaderFromBlockLocatorRequest {
+                    best_block_hash: hash_bytes(best_hash),
+                    block_locator_hashes: locator.iter().map(hash_bytes).collect(),
+                },
+                |mut client, request| async move {
+                    client
+                        .get_latest_block_header_from_block_locator(request)
+                        .await
+                },
+            )
+            .await?;
+
+        HeaderWithMeta::try_from(response)
+    }
+
+    /// Get the best block height and the median time past of the chain
+    pub async fn get_best_height_and_time(&mut self) -> Result<BestHeightAndTime> {
+        let response = self
+            .query_blockchain((), |mut client, request| async move {
+                client.get_best_height_and_time(request).await
+            })
+            .await?;
+
+        Ok(BestHeightAndTime {
+            height: response.height,
+            median_time: response.time,
+        })
+    }
+
+    /// Get a block and all of its known descendants, oldest first
+    ///
+    /// These are the blocks that invalidating the block would mark invalid,
+    /// or that revalidating it would restore. Descendants are found by
+    /// walking back from every chain tip above the block, so branches of
+    /// every status are included.
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block
+    pub async fn get_block_and_descendants(
+        &mut self,
+        hash: &BlockHash,
+    ) -> Result<Vec<HeaderWithMeta>> {
+        let block = self.get_block_header(hash).await?;
+        let height = block.meta.height;
+        let mut seen = HashSet::from([*hash]);
+        let mut headers = vec![block];
+
+        for tip in self.get_chain_tips().await? {
+            if tip.height <= height {
+                continue;
+            }
+            let depth = tip.height - height;
+            if self.get_hash_of_ancestor_block(&tip.hash, depth).await? != *hash {
+                continue;
+            }
+            for header in self.get_block_headers(&tip.hash, depth as u64).await? {
+                if seen.insert(header.hash()) {
+                    headers.push(header);
+                }
+            }
+        }
+
+        headers.sort_by_key(|h| h.meta.height);
+        Ok(headers)
+    }
+
+    /// Mark a block and all of its descendants as invalid
+    ///
+    /// If the block is on the active chain, the node reorganizes to the best
+    /// remaining valid chain.
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block to invalidate
+    ///
+    /// # Returns
+    /// The hashes of the blocks that were invalidated
+    pub async fn invalidate_block(&mut self, hash: &BlockHash) -> Result<Vec<BlockHash>> {
+        let response = self
+            .send_blockchain(
+                InvalidateBlockRequest {
+                    block_hash: hash_bytes(hash),
+                },
+                |mut client, request| async move { client.invalidate_block(request).await },
+            )
+            .await?;
+
+        response
+            .invalidated_blocks
+            .iter()
+            .map(|hash| decode_block_hash(hash))
+            .collect()
+    }
+
+    /// Clear the invalid mark from a block and its descendants
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block to revalidate
+    pub async fn revalidate_block(&mut self, hash: &BlockHash) -> Result<()> {
+        self.send_blockchain(
+            RevalidateBlockRequest {
+                block_hash: hash_bytes(hash),
+            },
+            |mut client, request| async move { client.revalidate_block(request).await },
+        )
+        .await
+    }
+
+    /// Get the value stored under a key in the blockchain service's state
+    ///
+    /// Fails with [`TeranodeError::NotFound`] if nothing is stored under the key.
+    ///
+    /// # Arguments
+    /// * `key` - The state key
+    pub async fn get_state(&mut self, key: &str) -> Result<Vec<u8>> {
+        let response = self
+            .query_blockchain(
+                GetStateRequest {
+                    key: key.to_string(),
+                },
+                |mut client, request| async move { client.get_state(request).await },
+            )
+            .await?;
+
+        Ok(response.data)
+    }
+
+    /// Store a value under a key in the blockchain service's state
+    ///
+    /// # Arguments
+    /// * `key` - The state key
+    /// * `data` - The value to store, replacing any existing value
+    pub async fn set_state(&mut self, key: &str, data: impl Into<Vec<u8>>) -> Result<()> {
+        self.send_blockchain(
+            SetStateRequest {
+                key: key.to_string(),
+                data: data.into(),
+            },
+            |mut client, request| async move { client.set_state(request).await },
+        )
+        .await
+    }
+
+    /// Get a JSON-encoded state value and deserialize it
+    ///
+    /// # Arguments
+    /// * `key` - The state key
+    #[cfg(feature = "serde")]
+    pub async fn get_state_json<T: serde::de::DeserializeOwned>(&mut self, key: &str) -> Result<T> {
+        let data = self.get_state(key).await?;
+        serde_json::from_slice(&data).map_err(|e| {
+            TeranodeError::DecodeError(format!("State value for {} is not valid JSON: {}", key, e))
+        })
+    }
+
+    /// Serialize a value as JSON and store it in the state
+    ///
+    /// # Arguments
+    /// * `key` - The state key
+    /// * `value` - The value to store, replacing any existing value
+    #[cfg(feature = "serde")]
+    pub async fn set_state_json<T: serde::Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<()> {
+        let data = serde_json::to_vec(value).map_err(|e| {
+            TeranodeError::InvalidArgument(format!("Cannot encode state value for {}: {}", key, e))
+        })?;
+        self.set_state(key, data).await
+    }
+
+    /// Check whether a block is marked as mined
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block
+    pub async fn get_block_is_mined(&mut self, hash: &BlockHash) -> Result<bool> {
+        let response = self
+            .query_blockchain(
+                GetBlockIsMinedRequest {
+                    block_hash: hash_bytes(hash),
+                },
+                |mut client, request| async move { client.get_block_is_mined(request).await },
+            )
+            .await?;
+
+        Ok(response.is_mined)
+    }
+
+    /// Mark a block as mined
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block
+    pub async fn set_block_mined_set(&mut self, hash: &BlockHash) -> Result<()> {
+        self.send_blockchain(
+            SetBlockMinedSetRequest {
+                block_hash: hash_bytes(hash),
+            },
+            |mut client, request| async move { client.set_block_mined_set(request).await },
+        )
+        .await
+    }
+
+    /// Get the blocks that are not yet marked as mined
+    pub async fn get_blocks_mined_not_set(&mut self) -> Result<Vec<Block>> {
+        let response = self
+            .query_blockchain((), |mut client, request| async move {
+                client.get_blocks_mined_not_set(request).await
+            })
+            .await?;
+
+        decode_blocks(&response.block_bytes)
+    }
+
+    /// Mark a block's subtrees as set
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block
+    pub async fn set_block_subtrees_set(&mut self, hash: &BlockHash) -> Result<()> {
+        self.send_blockchain(
+            SetBlockSubtreesSetRequest {
+                block_hash: hash_bytes(hash),
+            },
+            |mut client, request| async move { client.set_block_subtrees_set(request).await },
+        )
+        .await
+    }
+
+    /// Get the blocks whose subtrees are not yet set
+    pub async fn get_blocks_subtrees_not_set(&mut self) -> Result<Vec<Block>> {
+        let response = self
+            .query_blockchain((), |mut client, request| async move {
+                client.get_blocks_subtrees_not_set(request).await
+            })
+            .await?;
+
+        decode_blocks(&response.block_bytes)
+    }
+
+    /// Set a block's processed-at time to the node's current time
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block
+    pub async fn set_block_processed_at(&mut self, hash: &BlockHash) -> Result<()> {
+        self.send_block_processed_at(hash, false).await
+    }
+
+    /// Clear a block's processed-at time, marking it as not yet processed
+    ///
+    /// # Arguments
+    /// * `hash` - Hash of the block
+    pub async fn clear_block_processed_at(&mut self, hash: &BlockHash) -> Result<()> {
+        self.send_block_processed_at(hash, true).await
+    }
+
+    async fn send_block_processed_at(&mut self, hash: &BlockHash, clear: bool) -> Result<()> {
+        self.send_blockchain(
+            SetBlockProcessedAtRequest {
+                block_hash: hash_bytes(hash),
+                clear,
+            },
+            |mut client, request| async move { client.set_block_processed_at(request).await },
+        )
+        .await
+    }
+
+    /// Get the blocks waiting on mined and subtree processing
+    pub async fn get_backlog(&mut self) -> Result<Backlog> {
+        Ok(Backlog {
+            mined_not_set: PendingBlocks::new(self.get_blocks_mined_not_set().await?),
+            subtrees_not_set: PendingBlocks::new(self.get_blocks_subtrees_not_set().await?),
+        })
+    }
+
+    /// Get summary statistics for the current chain
+    pub async fn get_block_stats(&mut self) -> Result<BlockStats> {
+        let response = self
+            .query_blockchain((), |mut client, request| async move {
+                client.get_block_stats(request).await
+            })
+            .await?;
+
+        BlockStats::try_from(response)
+    }
+
+    /// Get the transaction count of each block stored within a recent period
+    ///
+    /// Use [`tx_per_second`](crate::stats::tx_per_second) and
+    /// [`block_intervals`](crate::stats::block_intervals) to turn the points
+    /// into series.
+    ///
+    /// # Arguments
+    /// * `period` - How far back from now to include blocks
+    pub async fn get_block_graph_data(&mut self, period: Duration) -> Result<Vec<DataPoint>> {
+        let response = self
+            .query_blockchain(
+                GetBlockGraphDataRequest {
+                    period_millis: period.as_millis() as u64,
+                },
+                |mut client, request| async move { client.get_block_graph_data(request).await },
+            )
+            .await?;
+
+        Ok(response
+            .data_points
+            .into_iter()
+            .map(DataPoint::from)
+            .collect())
+    }
+
+    /// Get the most recent blocks, highest first
+    ///
+    /// # Arguments
+    /// * `count` - Maximum number of blocks to return
+    /// * `include_orphans` - Whether to include blocks that are not on the active chain
+    /// * `from_height` - Highest block to include, or `None` to start at the tip
+    pub async fn get_last_n_blocks(
+        &mut self,
+        count: u32,
+        include_orphans: bool,
+        from_height: Option<u32>,
+    ) -> Result<Vec<BlockInfo>> {
+        let response = self
+            .query_blockchain(
+                GetLastNBlocksRequest {
+                    number_of_blocks: count as i64,
+                    include_orphans,
+                    from_height: from_height.unwrap_or(0),
+                },
+                |mut client, request| async move { client.get_last_n_blocks(request).await },
+            )
+            .await?;
+
+        response
+            .blocks
+            .into_iter()
+            .map(BlockInfo::try_from)
+            .collect()
+    }
+
+    /// Get the most recent blocks marked invalid, highest first
+    ///
+    /// # Arguments
+    /// * `count` - Maximum number of blocks to return
+    pub async fn get_last_n_invalid_blocks(&mut self, count: u32) -> Result<Vec<BlockInfo>> {
+        let response = self
+            .query_blockchain(
+                GetLastNInvalidBlocksRequest { n: count as i64 },
+                |mut client, request| async move {
+                    client.get_last_n_invalid_blocks(request).await
+                },
+            )
+            .await?;
+
+        response
+            .blocks
+            .into_iter()
+            .map(BlockInfo::try_from)
+            .collect()
+    }
+
+    /// Subscribe to blockchain notifications
+    ///
+    /// The returned stream never ends. If the underlying gRPC stream drops, the
+    /// subscription is re-established with exponential backoff and a
+    /// [`SubscriptionEvent::Gap`] is emitted to signal that notifications may
+    /// have been missed.
+    ///
+    /// # Arguments
+    /// * `source` - Identifier for this subscriber, reported to the node
+    pub fn subscribe(
+        &mut self,
+        source: impl Into<String>,
+    ) -> Result<impl Stream<Item = SubscriptionEvent> + Send + 'static> {
+        self.subscribe_with_backoff(source, ReconnectBackoff::default())
+    }
+
+    /// Subscribe to blockchain notifications with a custom reconnect backoff
+    ///
+    /// # Arguments
+    /// * `source` - Identifier for this subscriber, reported to the node
+    /// * `backoff` - Backoff applied between re-subscription attempts
+    pub fn subscribe_with_backoff(
+        &mut self,
+        source: impl Into<String>,
+        backoff: ReconnectBackoff,
+    ) -> Result<impl Stream<Item = SubscriptionEvent> + Send + 'static> {
+        let client = self.blockchain_client()?.clone();
+        Ok(subscription_stream(client, source.into(), backoff))
+    }
+
+    /// Get the current state of the blockchain FSM
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait for each attempt
+    pub async fn get_fsm_current_state(&mut self, timeout: Duration) -> Result<FsmState> {
+        let response = self
+            .query_blockchain_within(Some(timeout), (), |mut client, request| async move {
+                client.get_fsm_current_state(request).await
+            })
+            .await?;
+
+        FsmState::try_from(response.state)
+    }
+
+    /// Send an event to the blockchain FSM
+    ///
+    /// # Arguments
+    /// * `event` - The event to send
+    /// * `timeout` - Maximum time to wait for the call
+    ///
+    /// # Returns
+    /// The state of the FSM after handling the event
+    pub async fn send_fsm_event(&mut self, event: FsmEvent, timeout: Duration) -> Result<FsmState> {
+        let response = self
+            .send_blockchain_within(
+                Some(timeout),
+                SendFsmEventRequest {
+                    event: FsmEventType::from(event) as i32,
+                },
+                |mut client, request| async move { client.send_fsm_event(request).await },
+            )
+            .await?;
+
+        FsmState::try_from(response.state)
+    }
+
+    /// Wait for the blockchain FSM to reach `state`
+    ///
+    /// # Arguments
+    /// * `state` - The state to wait for
+    /// * `timeout` - Maximum time to wait on each attempt
+    pub async fn wait_fsm_to_transition_to_given_state(
+        &mut self,
+        state: FsmState,
+        timeout: Duration,
+    ) -> Result<()> {
+        self.query_blockchain_within(
+            Some(timeout),
+            WaitFsmToTransitionRequest {
+                state: FsmStateType::from(state) as i32,
+            },
+            |mut client, request| async move {
+                client.wait_fsm_to_transition_to_given_state(request).await
+            },
+        )
+        .await
+    }
+
+    /// Wait for the blockchain FSM to leave the IDLE state
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait on each attempt
+    pub async fn wait_until_fsm_transition_from_idle_state(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<()> {
+        self.query_blockchain_within(Some(timeout), (), |mut client, request| async move {
+            client
+                .wait_until_fsm_transition_from_idle_state(request)
+                .await
+        })
+        .await
+    }
+
+    /// Transition the blockchain service to the RUNNING state
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait for the call
+    pub async fn run(&mut self, timeout: Duration) -> Result<()> {
+        self.send_blockchain_within(Some(timeout), (), |mut client, request| async move {
+            client.run(request).await
+        })
+        .await
+    }
+
+    /// Start the block catch-up process
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait for the call
+    pub async fn catch_up_blocks(&mut self, timeout: Duration) -> Result<()> {
+        self.send_blockchain_within(Some(timeout), (), |mut client, request| async move {
+            client.catch_up_blocks(request).await
+        })
+        .await
+    }
+
+    /// Start the legacy synchronization process
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait for the call
+    pub async fn legacy_sync(&mut self, timeout: Duration) -> Result<()> {
+        self.send_blockchain_within(Some(timeout), (), |mut client, request| async move {
+            client.legacy_sync(request).await
+        })
+        .await
+    }
+
+    /// Transition the blockchain service to the IDLE state
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait for the call
+    pub async fn idle(&mut self, timeout: Duration) -> Result<()> {
+        self.send_blockchain_within(Some(timeout), (), |mut client, request| async move {
+            client.idle(request).await
+        })
+        .await
+    }
+
+    /// Get the list of peers
+    ///
+    /// # Returns
+    /// A response containing the list of connected peers
+    pub async fn get_peers(&mut self) -> Result<GetPeersResponse> {
+        self.query_peer((), |mut client, request| async move {
+            client.get_peers(request).await
+        })
+        .await
+    }
+
+    /// Ban an IP address or subnet until the given time
+    ///
+    /// # Arguments
+    /// * `target` - Address or subnet to ban
+    /// * `until` - When the ban expires
+    pub async fn ban_peer(&mut self, target: impl Into<IpSubnet>, until: SystemTime) -> Result<()> {
+        let target = target.into();
+        let until = until
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| {
+                TeranodeError::InvalidArgument("Ban expiry is before the Unix epoch".to_string())
+            })?
+            .as_secs() as i64;
+
+        let response = self
+            .send_peer(
+                BanPeerRequest {
+                    addr: target.to_string(),
+                    until,
+                },
+                |mut client, request| async move { client.ban_peer(request).await },
+            )
+            .await?;
+
+        if !response.ok {
+            return Err(TeranodeError::RequestRejected(format!(
+                "Peer service refused to ban {}",
+                target
+            )));
+        }
+        Ok(())
+    }
+
+    /// Lift the ban on an IP address or subnet
+    ///
+    /// # Arguments
+    /// * `target` - Address or subnet to unban
+    pub async fn unban_peer(&mut self, target: impl Into<IpSubnet>) -> Result<()> {
+        let target = target.into();
+        let response = self
+            .send_peer(
+                UnbanPeerRequest {
+                    addr: target.to_string(),
+                },
+                |mut client, request| async move { client.unban_peer(request).await },
+            )
+            .await?;
+
+        if !response.ok {
+            return Err(TeranodeError::RequestRejected(format!(
+                "Peer service refused to unban {}",
+                target
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check whether an IP address or subnet is banned
+    ///
+    /// # Arguments
+    /// * `target` - Address or subnet to check
+    pub async fn is_banned(&mut self, target: impl Into<IpSubnet>) -> Result<bool> {
+        let target = target.into();
+        let response = self
+            .query_peer(
+                IsBannedRequest {
+                    ip_or_subnet: target.to_string(),
+                },
+                |mut client, request| async move { client.is_banned(request).await },
+            )
+            .await?;
+
+        Ok(response.is_banned)
+    }
+
+    /// List all banned addresses and subnets
+    pub async fn list_banned(&mut self) -> Result<Vec<IpSubnet>> {
+        let response = self
+            .query_peer((), |mut client, request| async move {
+                client.list_banned(request).await
+            })
+            .await?;
+
+        response
+            .banned
+            .iter()
+            .map(|entry| {
+                entry.parse().map_err(|_| {
+                    TeranodeError::DecodeError(format!("Invalid banned entry: {}", entry))
+                })
+            })
+            .collect()
+    }
+
+    /// Remove all bans
+    pub async fn clear_banned(&mut self) -> Result<()> {
+        let response = self
+            .send_peer((), |mut client, request| async move {
+                client.clear_banned(request).await
+            })
+            .await?;
+
+        if !response.ok {
+            return Err(TeranodeError::RequestRejected(
+                "Peer service refused to clear bans".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Increase the ban score of a peer
+    ///
+    /// # Arguments
+    /// * `peer_id` - ID of the peer
+    /// * `reason` - Reason for the increase
+    pub async fn add_ban_score(&mut self, peer_id: &PeerId, reason: &str) -> Result<()> {
+        let response = self
+            .send_peer(
+                AddBanScoreRequest {
+                    peer_id: peer_id.to_string(),
+                    reason: reason.to_string(),
+                },
+                |mut client, request| async move { client.add_ban_score(request).await },
+            )
+            .await?;
+
+        if !response.ok {
+            return Err(TeranodeError::RequestRejected(format!(
+                "Peer service refused to add ban score for {}",
+                peer_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Connect the node to a peer
+    ///
+    /// # Arguments
+    /// * `addr` - Full peer address, including the trailing `/p2p` peer ID
+    pub async fn connect_peer(&mut self, addr: &Multiaddr) -> Result<()> {
+        validate_peer_multiaddr(addr)?;
+
+        let response = self
+            .send_peer(
+                ConnectPeerRequest {
+                    peer_address: addr.to_string(),
+                },
+                |mut client, request| async move { client.connect_peer(request).await },
+            )
+            .await?;
+
+        if !response.success {
+            return Err(TeranodeError::RequestRejected(format!(
+                "Peer service failed to connect to {}: {}",
+                addr, response.error
+            )));
+        }
+        Ok(())
+    }
+
+    /// Disconnect the node from a peer
+    ///
+    /// # Arguments
+    /// * `peer_id` - ID of the peer to disconnect
+    pub async fn disconnect_peer(&mut self, peer_id: &PeerId) -> Result<()> {
+        let response = self
+            .send_peer(
+                DisconnectPeerRequest {
+                    peer_id: peer_id.to_string(),
+                },
+                |mut client, request| async move { client.disconnect_peer(request).await },
+            )
+            .await?;
+
+        if !response.success {
+            return Err(TeranodeError::RequestRejected(format!(
+                "Peer service failed to disconnect {}: {}",
+                peer_id, response.error
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Make a call, applying the retry policy and the optional per-attempt deadline
+///
+/// Each attempt gets the full `timeout`, so an attempt that hangs is abandoned
+/// and retried rather than using up the time left for the others.
+async fn execute<C, R, T, F, Fut>(
+    client: C,
+    request: R,
+    call: F,
+    retry_policy: &RetryPolicy,
+    timeout: Option<Duration>,
+) -> Result<T>
+where
+    C: Clone,
+    R: Clone,
+    F: Fn(C, Request<R>) -> Fut,
+    Fut: Future<Output = std::result::Result<Response<T>, Status>>,
+{
+    let result = retry_policy
+        .retry(|| {
+            let request = match timeout {
+                Some(timeout) => request_with_timeout(request.clone(), timeout),
+                None => Request::new(request.clone()),
+            };
+            let attempt = call(client.clone(), request);
+            async move {
+                let result = match timeout {
+                    Some(timeout) => match tokio::time::timeout(timeout, attempt).await {
+                        Ok(result) => result,
+                        Err(_) => return Err(Status::deadline_exceeded("Request timed out")),
+                    },
+                    None => attempt.await,
+                };
+                // The channel reports its own deadline as CANCELLED
+                match result {
+                    Err(status) if is_timeout(&status) => {
+                        Err(Status::deadline_exceeded(status.message()))
+                    }
+                    result => result,
+                }
+            }
+        })
+        .await;
+
+    match (result, timeout) {
+        (Ok(response), _) => Ok(response.into_inner()),
+        (Err(status), Some(timeout)) if status.code() == Code::DeadlineExceeded => {
+            Err(TeranodeError::Timeout(timeout))
+        }
+        (Err(status), _) => Err(status.into()),
+    }
+}
+
+/// Whether a failed call ran out of time, on the server or in the client's channel
+fn is_timeout(status: &Status) -> bool {
+    if status.code() == Code::DeadlineExceeded {
+        return true;
+    }
+    let mut source = std::error::Error::source(status);
+    while let Some(error) = source {
+        if error.is::<tonic::TimeoutExpired>() {
+            return true;
+        }
+        source = error.source();
+    }
+    false
+}
+
+/// Build a request carrying a gRPC deadline
+fn request_with_timeout<T>(message: T, timeout: Duration) -> Request<T> {
+    let mut request = Request::new(message);
+    request.set_timeout(timeout);
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::serve_with_blocks;
+
+    #[tokio::test]
+    async fn test_state_against_mock() {
+        let (_mock, _server, mut client) = serve_with_blocks(0).await;
+
+        let err = client.get_state("missing").await.unwrap_err();
+        assert!(matches!(err, TeranodeError::NotFound(_)));
+
+        client.set_state("raw", vec![1, 2, 3]).await.unwrap();
+        assert_eq!(client.get_state("raw").await.unwrap(), vec![1, 2, 3]);
+
+        #[cfg(feature = "serde")]
+        {
+            client.set_state_json("height", &[7u32, 8]).await.unwrap();
+            assert_eq!(client.get_state("height").await.unwrap(), b"[7,8]");
+            let height: Vec<u32> = client.get_state_json("height").await.unwrap();
+            assert_eq!(height, vec![7, 8]);
+            assert!(client.get_state_json::<u32>("raw").await.is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_and_revalidate_against_mock() {
+        let (mock, _server, mut client) = serve_with_blocks(10).await;
+        let branch = mock.fork(6, 2);
+
+        // Block 5 has descendants on both the active chain and the fork
+        let hash = mock.block_at(5).unwrap().hash();
+        let affected = client.get_block_and_descendants(&hash).await.unwrap();
+        let heights: Vec<u32> = affected.iter().map(|h| h.meta.height).collect();
+        assert_eq!(heights, vec![5, 6, 7, 7, 8, 8, 9, 10]);
+
+        let invalidated = client.invalidate_block(&branch[0]).await.unwrap();
+        assert_eq!(invalidated, branch);
+        let tips = client.get_chain_tips().await.unwrap();
+        assert_eq!(tips[1].status, crate::ChainTipStatus::Invalid);
+
+        client.revalidate_block(&branch[0]).await.unwrap();
+        let header = client.get_block_header(&branch[1]).await.unwrap();
+        assert!(!header.meta.invalid);
+    }
+}
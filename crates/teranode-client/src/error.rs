@@ -0,0 +1,75 @@
+//! Error types for Teranode client operations
+
+use std::time::Duration;
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, TeranodeError>;
+
+#[derive(Error, Debug)]
+pub enum TeranodeError {
+    #[error("gRPC connection error: {0}")]
+    ConnectionError(#[from] tonic::transport::Error),
+
+    #[error("gRPC status error: {0}")]
+    GrpcError(Box<tonic::Status>),
+
+    #[error("Invalid configuration: {0}")]
+    ConfigError(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("{0} service not configured")]
+    ServiceNotConfigured(&'static str),
+
+    #[error("Decode error: {0}")]
+    DecodeError(String),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    #[error("Request rejected: {0}")]
+    RequestRejected(String),
+
+    #[error("Request timed out after {0:?}")]
+    Timeout(Duration),
+
+    #[error("Header store error: {0}")]
+    StoreError(#[from] std::io::Error),
+}
+
+impl TeranodeError {
+    /// The gRPC status code behind this error, if it came from the server
+    pub fn code(&self) -> Option<tonic::Code> {
+        match self {
+            TeranodeError::GrpcError(status) => Some(status.code()),
+            TeranodeError::NotFound(_) => Some(tonic::Code::NotFound),
+            _ => None,
+        }
+    }
+}
+
+impl From<tonic::Status> for TeranodeError {
+    fn from(status: tonic::Status) -> Self {
+        match status.code() {
+            tonic::Code::NotFound => TeranodeError::NotFound(status.message().to_string()),
+            _ => TeranodeError::GrpcError(Box::new(status)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_mapping() {
+        let err = TeranodeError::from(tonic::Status::not_found("block abc"));
+        assert!(matches!(err, TeranodeError::NotFound(ref m) if m == "block abc"));
+        assert_eq!(err.code(), Some(tonic::Code::NotFound));
+
+        let err = TeranodeError::from(tonic::Status::unavailable("down"));
+        assert!(matches!(err, TeranodeError::GrpcError(_)));
+        assert_eq!(err.code(), Some(tonic::Code::Unavailable));
+    }
+}
@@ -0,0 +1,185 @@
+//! Chain tips and fork analysis
+//!
+//! Teranode tracks every branch of the block tree it knows about. These
+//! types decode `GetChainTips` and describe how each competing branch relates
+//! to the active chain, to help diagnose reorgs and stale branches.
+
+use crate::error::{Result, TeranodeError};
+use crate::header::HeaderWithMeta;
+use crate::proto::model::ChainTip as ProtoChainTip;
+use bitcoinsv::bitcoin::{BlockHash, FromHex};
+use std::fmt;
+use std::str::FromStr;
+
+/// Validation status of a chain tip
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChainTipStatus {
+    /// Tip of the active chain
+    Active,
+    /// Fully validated branch that is not part of the active chain
+    ValidFork,
+    /// Headers are valid but not all blocks are available
+    ValidHeaders,
+    /// Only the headers have been received
+    HeadersOnly,
+    /// Branch contains at least one invalid block
+    Invalid,
+    /// Status not known to this client
+    Other(String),
+}
+
+impl FromStr for ChainTipStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => ChainTipStatus::Active,
+            "valid-fork" => ChainTipStatus::ValidFork,
+            "valid-headers" => ChainTipStatus::ValidHeaders,
+            "headers-only" => ChainTipStatus::HeadersOnly,
+            "invalid" => ChainTipStatus::Invalid,
+            other => ChainTipStatus::Other(other.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for ChainTipStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ChainTipStatus::Active => "active",
+            ChainTipStatus::ValidFork => "valid-fork",
+            ChainTipStatus::ValidHeaders => "valid-headers",
+            ChainTipStatus::HeadersOnly => "headers-only",
+            ChainTipStatus::Invalid => "invalid",
+            ChainTipStatus::Other(other) => other,
+        };
+        f.write_str(s)
+    }
+}
+
+/// A tip of the block tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainTip {
+    /// Height of the tip
+    pub height: u32,
+
+    /// Hash of the tip
+    pub hash: BlockHash,
+
+    /// Number of blocks since the branch left the active chain, 0 for the active tip
+    pub branch_len: u32,
+
+    /// Validation status of the branch
+    pub status: ChainTipStatus,
+}
+
+impl ChainTip {
+    /// Whether this is the tip of the active chain
+    pub fn is_active(&self) -> bool {
+        self.status == ChainTipStatus::Active
+    }
+}
+
+impl TryFrom<ProtoChainTip> for ChainTip {
+    type Error = TeranodeError;
+
+    fn try_from(tip: ProtoChainTip) -> Result<Self> {
+        let hash = BlockHash::from_hex(&tip.hash).map_err(|_| {
+            TeranodeError::DecodeError(format!("Invalid chain tip hash: {}", tip.hash))
+        })?;
+
+        Ok(Self {
+            height: tip.height,
+            hash,
+            branch_len: tip.branchlen,
+            status: tip.status.parse().expect("infallible"),
+        })
+    }
+}
+
+/// A branch competing with the active chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fork {
+    /// The tip of the branch
+    pub tip: ChainTip,
+
+    /// Last block shared by the branch and the active chain
+    pub fork_point: HeaderWithMeta,
+
+    /// Headers of the branch after the fork point, oldest first
+    pub branch: Vec<HeaderWithMeta>,
+
+    /// Headers of the active chain at the same heights as `branch`, oldest first
+    ///
+    /// Shorter than `branch` when the branch is longer than the active chain.
+    pub competing: Vec<HeaderWithMeta>,
+}
+
+impl Fork {
+    /// Number of blocks on the branch after the fork point
+    pub fn branch_len(&self) -> u32 {
+        self.branch.len() as u32
+    }
+
+    /// Height of the fork point
+    pub fn fork_height(&self) -> u32 {
+        self.fork_point.meta.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::serve_with_blocks;
+
+    #[test]
+    fn test_chain_tip_from_proto() {
+        let hash = "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206";
+        let tip = ChainTip::try_from(ProtoChainTip {
+            height: 12,
+            hash: hash.to_string(),
+            branchlen: 2,
+            status: "valid-fork".to_string(),
+        })
+        .unwrap();
+        assert_eq!(tip.hash.to_string(), hash);
+        assert_eq!(tip.status, ChainTipStatus::ValidFork);
+        assert!(!tip.is_active());
+
+        let status: ChainTipStatus = "unknown".parse().unwrap();
+        assert_eq!(status.to_string(), "unknown");
+
+        let bad = ProtoChainTip {
+            hash: "xyz".to_string(),
+            ..Default::default()
+        };
+        assert!(ChainTip::try_from(bad).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fork_analysis_against_mock() {
+        let (mock, _server, mut client) = serve_with_blocks(10).await;
+        let branch = mock.fork(6, 2);
+
+        let tips = client.get_chain_tips().await.unwrap();
+        assert_eq!(tips.len(), 2);
+        assert!(tips[0].is_active());
+
+        let forks = client.get_forks().await.unwrap();
+        let fork = &forks[0];
+        assert_eq!(fork.tip.hash, branch[1]);
+        assert_eq!(fork.fork_height(), 6);
+        assert_eq!(fork.branch_len(), 2);
+        let hashes: Vec<BlockHash> = fork.branch.iter().map(|h| h.hash()).collect();
+        assert_eq!(hashes, branch);
+        let heights: Vec<u32> = fork.competing.iter().map(|h| h.meta.height).collect();
+        assert_eq!(heights, vec![7, 8]);
+
+        // A tip under-reporting its branch length still finds the fork point
+        let mut tip = fork.tip.clone();
+        tip.branch_len = 1;
+        let fork = client.analyze_fork(tip).await.unwrap();
+        assert_eq!(fork.fork_height(), 6);
+        assert_eq!(fork.branch_len(), 2);
+    }
+}
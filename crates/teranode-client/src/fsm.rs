@@ -0,0 +1,148 @@
+//! Blockchain finite state machine types
+//!
+//! Teranode's blockchain service drives the node through a small state machine.
+//! These enums mirror `FSMStateType` and `FSMEventType` from the protobuf
+//! definitions so callers don't have to deal with raw integers.
+
+use crate::error::{Result, TeranodeError};
+use crate::proto::blockchain_api::{FsmEventType, FsmStateType};
+use std::fmt;
+use std::str::FromStr;
+
+/// State of the blockchain FSM
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FsmState {
+    /// Service is idle
+    Idle,
+    /// Service is running normally
+    Running,
+    /// Service is catching up blocks
+    CatchingBlocks,
+    /// Service is in legacy sync mode
+    LegacySyncing,
+}
+
+/// Event that can be sent to the blockchain FSM
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FsmEvent {
+    /// Move the service to the IDLE state
+    Stop,
+    /// Start normal operation
+    Run,
+    /// Start catching up blocks
+    CatchUpBlocks,
+    /// Start legacy sync mode
+    LegacySync,
+}
+
+impl From<FsmState> for FsmStateType {
+    fn from(state: FsmState) -> Self {
+        match state {
+            FsmState::Idle => FsmStateType::Idle,
+            FsmState::Running => FsmStateType::Running,
+            FsmState::CatchingBlocks => FsmStateType::Catchingblocks,
+            FsmState::LegacySyncing => FsmStateType::Legacysyncing,
+        }
+    }
+}
+
+impl From<FsmStateType> for FsmState {
+    fn from(state: FsmStateType) -> Self {
+        match state {
+            FsmStateType::Idle => FsmState::Idle,
+            FsmStateType::Running => FsmState::Running,
+            FsmStateType::Catchingblocks => FsmState::CatchingBlocks,
+            FsmStateType::Legacysyncing => FsmState::LegacySyncing,
+        }
+    }
+}
+
+impl TryFrom<i32> for FsmState {
+    type Error = TeranodeError;
+
+    fn try_from(value: i32) -> Result<Self> {
+        FsmStateType::try_from(value)
+            .map(FsmState::from)
+            .map_err(|_| TeranodeError::DecodeError(format!("Unknown FSM state: {}", value)))
+    }
+}
+
+impl From<FsmEvent> for FsmEventType {
+    fn from(event: FsmEvent) -> Self {
+        match event {
+            FsmEvent::Stop => FsmEventType::Stop,
+            FsmEvent::Run => FsmEventType::Run,
+            FsmEvent::CatchUpBlocks => FsmEventType::Catchupblocks,
+            FsmEvent::LegacySync => FsmEventType::Legacysync,
+        }
+    }
+}
+
+impl fmt::Display for FsmState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(FsmStateType::from(*self).as_str_name())
+    }
+}
+
+impl fmt::Display for FsmEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(FsmEventType::from(*self).as_str_name())
+    }
+}
+
+impl FromStr for FsmState {
+    type Err = TeranodeError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        FsmStateType::from_str_name(&s.to_ascii_uppercase())
+            .map(FsmState::from)
+            .ok_or_else(|| TeranodeError::InvalidArgument(format!("Unknown FSM state: {}", s)))
+    }
+}
+
+impl FromStr for FsmEvent {
+    type Err = TeranodeError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match FsmEventType::from_str_name(&s.to_ascii_uppercase()) {
+            Some(FsmEventType::Stop) => Ok(FsmEvent::Stop),
+            Some(FsmEventType::Run) => Ok(FsmEvent::Run),
+            Some(FsmEventType::Catchupblocks) => Ok(FsmEvent::CatchUpBlocks),
+            Some(FsmEventType::Legacysync) => Ok(FsmEvent::LegacySync),
+            None => Err(TeranodeError::InvalidArgument(format!(
+                "Unknown FSM event: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_round_trip() {
+        for state in [
+            FsmState::Idle,
+            FsmState::Running,
+            FsmState::CatchingBlocks,
+            FsmState::LegacySyncing,
+        ] {
+            let proto = FsmStateType::from(state) as i32;
+            assert_eq!(FsmState::try_from(proto).unwrap(), state);
+            assert_eq!(state.to_string().parse::<FsmState>().unwrap(), state);
+        }
+        assert!(FsmState::try_from(42).is_err());
+    }
+
+    #[test]
+    fn test_event_parse() {
+        assert_eq!(
+            "catchupblocks".parse::<FsmEvent>().unwrap(),
+            FsmEvent::CatchUpBlocks
+        );
+        assert_eq!(FsmEvent::LegacySync.to_string(), "LEGACYSYNC");
+        assert!("jump".parse::<FsmEvent>().is_err());
+    }
+}
@@ -0,0 +1,290 @@
+//! Decoded block header types
+//!
+//! Teranode returns block headers as raw 80-byte serialized headers, usually
+//! accompanied by a serialized `BlockHeaderMeta` describing where the block sits
+//! in the chain. These types pair the two in decoded form.
+
+use crate::error::{Result, TeranodeError};
+use crate::proto::blockchain_api::GetBlockHeaderResponse;
+use bitcoinsv::bitcoin::{BlockHash, BlockHeader, Hash};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Size of the fixed portion of a serialized `BlockHeaderMeta`
+const META_FIXED_SIZE: usize = 64;
+
+/// Metadata that Teranode stores alongside each block header
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockHeaderMeta {
+    /// Internal block identifier
+    pub id: u32,
+
+    /// Block height
+    pub height: u32,
+
+    /// Number of transactions in the block
+    pub tx_count: u64,
+
+    /// Size of the block in bytes
+    pub size_in_bytes: u64,
+
+    /// Miner identifier, as extracted from the coinbase
+    pub miner: String,
+
+    /// Peer the block was received from
+    pub peer_id: String,
+
+    /// Block timestamp
+    pub block_time: u32,
+
+    /// Time the block was stored by Teranode
+    pub timestamp: u32,
+
+    /// Accumulated chain work, big-endian
+    pub chain_work: Vec<u8>,
+
+    /// Whether the block has been marked as mined
+    pub mined_set: bool,
+
+    /// Whether the block's subtrees have been processed
+    pub subtrees_set: bool,
+
+    /// Whether the block has been marked invalid
+    pub invalid: bool,
+
+    /// When block processing completed, if it has
+    pub processed_at: Option<SystemTime>,
+}
+
+impl BlockHeaderMeta {
+    /// Decode a serialized `BlockHeaderMeta` as returned in the `metas` field
+    ///
+    /// The layout is little-endian `id` (u32), `height` (u32), `tx_count` (u64),
+    /// `size_in_bytes` (u64), `block_time` (u32), `timestamp` (u32), followed by
+    /// 32 bytes of chain work, then optional `mined_set`, `subtrees_set` and
+    /// `invalid` flag bytes and the miner string.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < META_FIXED_SIZE {
+            return Err(TeranodeError::DecodeError(format!(
+                "Block header meta too short: {} bytes",
+                bytes.len()
+            )));
+        }
+
+        let u32_at = |offset: usize| {
+            u32::from_le_bytes(bytes[offset..offset + 4].try_into().expect("4 bytes"))
+        };
+        let u64_at = |offset: usize| {
+            u64::from_le_bytes(bytes[offset..offset + 8].try_into().expect("8 bytes"))
+        };
+        let flag_at = |offset: usize| bytes.get(offset).is_some_and(|b| *b != 0);
+
+        let miner = bytes
+            .get(META_FIXED_SIZE + 3..)
+            .map(|m| String::from_utf8_lossy(m).into_owned())
+            .unwrap_or_default();
+
+        Ok(Self {
+            id: u32_at(0),
+            height: u32_at(4),
+            tx_count: u64_at(8),
+            size_in_bytes: u64_at(16),
+            miner,
+            peer_id: String::new(),
+            block_time: u32_at(24),
+            timestamp: u32_at(28),
+            chain_work: bytes[32..META_FIXED_SIZE].to_vec(),
+            mined_set: flag_at(META_FIXED_SIZE),
+            subtrees_set: flag_at(META_FIXED_SIZE + 1),
+            invalid: flag_at(META_FIXED_SIZE + 2),
+            processed_at: None,
+        })
+    }
+
+    /// Serialize in the layout read by [`from_bytes`](Self::from_bytes)
+    ///
+    /// `peer_id` and `processed_at` are not part of the serialized form.
+    /// Chain work longer than 32 bytes is truncated to its low-order bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(META_FIXED_SIZE + 3 + self.miner.len());
+        bytes.extend_from_slice(&self.id.to_le_bytes());
+        bytes.extend_from_slice(&self.height.to_le_bytes());
+        bytes.extend_from_slice(&self.tx_count.to_le_bytes());
+        bytes.extend_from_slice(&self.size_in_bytes.to_le_bytes());
+        bytes.extend_from_slice(&self.block_time.to_le_bytes());
+        bytes.extend_from_slice(&self.timestamp.to_le_bytes());
+
+        let mut chain_work = [0u8; 32];
+        let work = &self.chain_work[self.chain_work.len().saturating_sub(32)..];
+        chain_work[32 - work.len()..].copy_from_slice(work);
+        bytes.extend_from_slice(&chain_work);
+
+        bytes.push(self.mined_set as u8);
+        bytes.push(self.subtrees_set as u8);
+        bytes.push(self.invalid as u8);
+        bytes.extend_from_slice(self.miner.as_bytes());
+        bytes
+    }
+}
+
+/// A decoded block header together with its Teranode metadata
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderWithMeta {
+    pub header: BlockHeader,
+    pub meta: BlockHeaderMeta,
+}
+
+impl HeaderWithMeta {
+    /// Hash of the block header
+    pub fn hash(&self) -> BlockHash {
+        self.header.hash()
+    }
+}
+
+impl TryFrom<GetBlockHeaderResponse> for HeaderWithMeta {
+    type Error = TeranodeError;
+
+    fn try_from(response: GetBlockHeaderResponse) -> Result<Self> {
+        let processed_at = response.processed_at.as_ref().map(decode_timestamp);
+
+        Ok(Self {
+            header: decode_header(&response.block_header)?,
+            meta: BlockHeaderMeta {
+                id: response.id,
+                height: response.height,
+                tx_count: response.tx_count,
+                size_in_bytes: response.size_in_bytes,
+                miner: response.miner,
+                peer_id: response.peer_id,
+                block_time: response.block_time,
+                timestamp: response.timestamp,
+                chain_work: response.chain_work,
+                mined_set: response.mined_set,
+                subtrees_set: response.subtrees_set,
+                invalid: response.invalid,
+                processed_at,
+            },
+        })
+    }
+}
+
+/// Decode a single serialized block header
+pub fn decode_header(bytes: &[u8]) -> Result<BlockHeader> {
+    if bytes.len() != BlockHeader::SIZE as usize {
+        return Err(TeranodeError::DecodeError(format!(
+            "Invalid block header length: expected {} bytes, got {}",
+            BlockHeader::SIZE,
+            bytes.len()
+        )));
+    }
+    Ok(BlockHeader::from_slice(bytes))
+}
+
+/// Decode parallel `blockHeaders` and `metas` lists into paired headers
+pub fn decode_headers_with_metas(
+    headers: &[Vec<u8>],
+    metas: &[Vec<u8>],
+) -> Result<Vec<HeaderWithMeta>> {
+    if headers.len() != metas.len() {
+        return Err(TeranodeError::DecodeError(format!(
+            "Mismatched header and meta counts: {} headers, {} metas",
+            headers.len(),
+            metas.len()
+        )));
+    }
+
+    headers
+        .iter()
+        .zip(metas)
+        .map(|(header, meta)| {
+            Ok(HeaderWithMeta {
+                header: decode_header(header)?,
+                meta: BlockHeaderMeta::from_bytes(meta)?,
+            })
+        })
+        .collect()
+}
+
+/// Encode a block hash for use in a request
+pub(crate) fn hash_bytes(hash: &BlockHash) -> Vec<u8> {
+    hash.raw.to_vec()
+}
+
+/// Convert Unix seconds, as used in block headers, to a `SystemTime`
+pub fn unix_time(seconds: u32) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(seconds as u64)
+}
+
+/// Convert a protobuf timestamp, clamping times before the epoch to the epoch
+pub(crate) fn decode_timestamp(ts: &prost_types::Timestamp) -> SystemTime {
+    UNIX_EPOCH + Duration::new(ts.seconds.max(0) as u64, ts.nanos.max(0) as u32)
+}
+
+/// Decode a block hash from a response, checking its length
+pub(crate) fn decode_block_hash(bytes: &[u8]) -> Result<BlockHash> {
+    if bytes.len() != Hash::SIZE as usize {
+        return Err(TeranodeError::DecodeError(format!(
+            "Invalid block hash length: {}",
+            bytes.len()
+        )));
+    }
+    Ok(Hash::from_slice(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoinsv::bitcoin::BlockchainId;
+
+    fn sample_meta() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&7u32.to_le_bytes());
+        bytes.extend_from_slice(&100u32.to_le_bytes());
+        bytes.extend_from_slice(&42u64.to_le_bytes());
+        bytes.extend_from_slice(&1_000u64.to_le_bytes());
+        bytes.extend_from_slice(&1_700_000_000u32.to_le_bytes());
+        bytes.extend_from_slice(&1_700_000_100u32.to_le_bytes());
+        let mut chain_work = [0u8; 32];
+        chain_work[31] = 0x02;
+        bytes.extend_from_slice(&chain_work);
+        bytes.extend_from_slice(&[1, 0, 1]);
+        bytes.extend_from_slice(b"/miner/");
+        bytes
+    }
+
+    #[test]
+    fn test_meta_from_bytes() {
+        let meta = BlockHeaderMeta::from_bytes(&sample_meta()).unwrap();
+        assert_eq!(meta.id, 7);
+        assert_eq!(meta.height, 100);
+        assert_eq!(meta.tx_count, 42);
+        assert_eq!(meta.size_in_bytes, 1_000);
+        assert_eq!(meta.block_time, 1_700_000_000);
+        assert_eq!(meta.timestamp, 1_700_000_100);
+        assert_eq!(meta.chain_work.len(), 32);
+        assert_eq!(meta.chain_work[31], 0x02);
+        assert!(meta.mined_set);
+        assert!(!meta.subtrees_set);
+        assert!(meta.invalid);
+        assert_eq!(meta.miner, "/miner/");
+        assert_eq!(meta.to_bytes(), sample_meta());
+    }
+
+    #[test]
+    fn test_meta_too_short() {
+        assert!(BlockHeaderMeta::from_bytes(&[0u8; 10]).is_err());
+    }
+
+    #[test]
+    fn test_decode_headers_with_metas() {
+        let genesis = BlockHeader::get_genesis(BlockchainId::Main);
+        let headers = vec![genesis.raw.to_vec()];
+
+        let decoded = decode_headers_with_metas(&headers, &[sample_meta()]).unwrap();
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].hash(), genesis.hash());
+        assert_eq!(decoded[0].meta.height, 100);
+
+        assert!(decode_headers_with_metas(&headers, &[]).is_err());
+        assert!(decode_headers_with_metas(&[vec![0u8; 79]], &[sample_meta()]).is_err());
+    }
+}
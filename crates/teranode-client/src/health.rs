@@ -0,0 +1,146 @@
+//! Service health
+//!
+//! The blockchain service answers HealthGRPC with an overall flag, a details
+//! string and the time of the check. The details hold the service's JSON
+//! report on each of its dependencies, which [`Health::dependencies`] decodes
+//! when the `serde` feature is enabled.
+
+use crate::header::decode_timestamp;
+use crate::proto::blockchain_api::HealthResponse;
+use std::time::SystemTime;
+
+/// Result of a HealthGRPC call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Health {
+    /// Whether the service considers itself healthy
+    pub ok: bool,
+
+    /// The service's report on its dependencies, usually JSON
+    pub details: String,
+
+    /// When the service ran the check, if reported
+    pub timestamp: Option<SystemTime>,
+}
+
+impl From<HealthResponse> for Health {
+    fn from(response: HealthResponse) -> Self {
+        Self {
+            ok: response.ok,
+            details: response.details,
+            timestamp: response.timestamp.as_ref().map(decode_timestamp),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Health {
+    /// Decode the per-dependency checks from the details
+    ///
+    /// Fails with [`DecodeError`](crate::TeranodeError::DecodeError) when the
+    /// details are not a JSON dependency report.
+    pub fn dependencies(&self) -> crate::Result<Vec<DependencyHealth>> {
+        #[derive(serde::Deserialize)]
+        struct Report {
+            #[serde(default)]
+            dependencies: Vec<DependencyHealth>,
+        }
+
+        serde_json::from_str::<Report>(&self.details)
+            .map(|report| report.dependencies)
+            .map_err(|e| {
+                crate::TeranodeError::DecodeError(format!("Invalid health details: {}", e))
+            })
+    }
+}
+
+/// Health of one dependency of a service, as listed in the health details
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct DependencyHealth {
+    /// Name of the dependency, e.g. a store or another service
+    pub resource: String,
+
+    /// HTTP-style status code, "200" when healthy
+    pub status: String,
+
+    /// Error reported by the check, "<nil>" or empty when there is none
+    #[serde(default)]
+    pub error: String,
+
+    /// Message reported by the check
+    #[serde(default)]
+    pub message: String,
+}
+
+#[cfg(feature = "serde")]
+impl DependencyHealth {
+    /// Whether the dependency reported a healthy status
+    pub fn is_ok(&self) -> bool {
+        self.status == "200"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockTeranode;
+    use crate::TeranodeClient;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_health_from_proto() {
+        let health = Health::from(HealthResponse {
+            ok: true,
+            details: String::new(),
+            timestamp: Some(prost_types::Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 0,
+            }),
+        });
+        assert!(health.ok);
+        assert_eq!(
+            health.timestamp,
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_health_dependencies() {
+        let health = Health {
+            ok: false,
+            details: r#"{"status":"503","dependencies":[
+                {"resource":"BlockchainStore","status":"200","error":"<nil>","message":"OK"},
+                {"resource":"Kafka","status":"503","error":"connection refused"}
+            ]}"#
+            .to_string(),
+            timestamp: None,
+        };
+
+        let dependencies = health.dependencies().unwrap();
+        assert_eq!(dependencies.len(), 2);
+        assert!(dependencies[0].is_ok());
+        assert!(!dependencies[1].is_ok());
+        assert_eq!(dependencies[1].error, "connection refused");
+
+        let plain = Health {
+            details: "not json".to_string(),
+            ..health
+        };
+        assert!(plain.dependencies().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_health_against_mock() {
+        let mock = MockTeranode::new();
+        let server = mock.serve().await.unwrap();
+        let mut client = TeranodeClient::connect(server.endpoint()).await.unwrap();
+
+        let health = client.health().await.unwrap();
+        assert!(health.ok);
+        assert!(health.timestamp.is_some());
+
+        mock.set_healthy(false);
+        assert!(!client.health().await.unwrap().ok);
+    }
+}
@@ -0,0 +1,289 @@
+//! Block locators and legacy-style header sync
+//!
+//! A block locator lists hashes from a chain, densely near the tip and then
+//! exponentially further apart back to genesis. A node receiving one finds the
+//! most recent hash it shares and replies with the headers that follow, which
+//! is how legacy Bitcoin peers catch up on headers. [`HeaderStore::sync`]
+//! drives the same exchange against the blockchain service's
+//! LocateBlockHeaders call.
+
+use crate::client::TeranodeClient;
+use crate::error::{Result, TeranodeError};
+use crate::store::{HeaderStore, StoredHeader};
+use crate::work::Target;
+use bitcoinsv::bitcoin::{BlockHash, BlockHeader};
+
+/// Number of most recent blocks included one by one before the steps start doubling
+pub const LOCATOR_DENSE_LEN: usize = 10;
+
+/// Maximum number of headers requested per LocateBlockHeaders call
+pub const MAX_LOCATE_HEADERS: u32 = 2000;
+
+/// Best block height and median time past, as reported by GetBestHeightAndTime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BestHeightAndTime {
+    /// Height of the best block
+    pub height: u32,
+
+    /// Median timestamp of the last 11 blocks
+    pub median_time: u32,
+}
+
+/// Heights of the blocks in a standard locator for a chain with tip `tip_height`
+///
+/// The last [`LOCATOR_DENSE_LEN`] blocks are listed one by one, then the gap
+/// doubles with each entry. Genesis is always the last entry.
+pub fn locator_heights(tip_height: u32) -> Vec<u32> {
+    let mut heights = Vec::new();
+    let mut height = tip_height as i64;
+    let mut step = 1;
+    while height > 0 {
+        heights.push(height as u32);
+        if heights.len() >= LOCATOR_DENSE_LEN {
+            step *= 2;
+        }
+        height -= step;
+    }
+    heights.push(0);
+    heights
+}
+
+impl HeaderStore {
+    /// Build a block locator from the stored chain, tip first
+    ///
+    /// Heights the store has no header for are skipped, so a store holding
+    /// only part of the chain still yields a usable locator.
+    pub fn locator(&self) -> Result<Vec<BlockHash>> {
+        let Some(tip) = self.tip()? else {
+            return Ok(Vec::new());
+        };
+
+        let mut locator = Vec::new();
+        for height in locator_heights(tip.height) {
+            if let Some(header) = self.get_by_height(height)? {
+                locator.push(header.hash());
+            }
+        }
+        Ok(locator)
+    }
+
+    /// Bring the store up to the node's best chain using block locators
+    ///
+    /// Repeatedly sends the store's locator to LocateBlockHeaders and stores
+    /// the headers that come back, until the node has nothing newer. An empty
+    /// store is first seeded with the genesis header. Only headers are
+    /// exchanged; their heights and chain work are computed locally. The
+    /// last header of each response is stored as the tip, so heights left
+    /// over from a longer chain the node has since abandoned are dropped.
+    ///
+    /// # Returns
+    /// The number of headers stored
+    pub async fn sync(&self, client: &TeranodeClient) -> Result<usize> {
+        let mut client = client.clone();
+        if self.tip()?.is_none() {
+            let genesis = client.get_block_header_by_height(0).await?;
+            self.insert_on_chain(&StoredHeader::try_from(&genesis)?)?;
+        }
+
+        let mut stored = 0;
+        loop {
+            let locator = self.locator()?;
+            let headers = client
+                .locate_block_headers(&locator, None, MAX_LOCATE_HEADERS)
+                .await?;
+
+            let mut added = 0;
+            for (i, header) in headers.iter().enumerate() {
+                // The node may repeat the fork point it found
+                if self.is_on_chain(&header.hash())? {
+                    continue;
+                }
+                let parent = self.get(&header.prev_hash())?.ok_or_else(|| {
+                    TeranodeError::InvalidArgument(format!(
+                        "Located header {} does not connect to the stored chain",
+                        header.hash()
+                    ))
+                })?;
+                let header = extend(&parent, header)?;
+                if i + 1 == headers.len() {
+                    self.insert_tip(&header)?;
+                } else {
+                    self.insert_on_chain(&header)?;
+                }
+                added += 1;
+            }
+
+            stored += added;
+            if added == 0 || (headers.len() as u32) < MAX_LOCATE_HEADERS {
+                return Ok(stored);
+            }
+        }
+    }
+
+    /// Whether a header is stored as part of the active chain
+    fn is_on_chain(&self, hash: &BlockHash) -> Result<bool> {
+        Ok(match self.get(hash)? {
+            Some(header) => self
+                .get_by_height(header.height)?
+                .is_some_and(|h| h.hash() == *hash),
+            None => false,
+        })
+    }
+}
+
+/// Height and chain work of a header built on `parent`, computed from the header alone
+fn extend(parent: &StoredHeader, header: &BlockHeader) -> Result<StoredHeader> {
+    let work = Target::from_compact(header.bits())?.work();
+    let chain_work = parent.chain_work.checked_add(work).ok_or_else(|| {
+        TeranodeError::InvalidArgument(format!(
+            "Chain work overflows 256 bits at header {}",
+            header.hash()
+        ))
+    })?;
+
+    Ok(StoredHeader {
+        header: header.clone(),
+        height: parent.height + 1,
+        chain_work,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockTeranode;
+    use crate::work::ChainWork;
+
+    #[test]
+    fn test_locator_heights() {
+        assert_eq!(locator_heights(0), vec![0]);
+        assert_eq!(locator_heights(3), vec![3, 2, 1, 0]);
+        assert_eq!(
+            locator_heights(30),
+            vec![30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 19, 15, 7, 0]
+        );
+
+        let heights = locator_heights(1_000_000);
+        assert!(heights.len() < 40);
+        assert_eq!(heights.last(), Some(&0));
+    }
+
+    #[test]
+    fn test_extend_overflow() {
+        let mock = MockTeranode::with_blocks(1);
+        let stored_at = |height| {
+            let block = mock.block_at(height).unwrap();
+            StoredHeader {
+                header: block.block.header,
+                height,
+                chain_work: ChainWork::from_be_bytes(&block.meta.chain_work).unwrap(),
+            }
+        };
+        let mut parent = stored_at(0);
+        let header = mock.block_at(1).unwrap().block.header;
+        assert_eq!(extend(&parent, &header).unwrap(), stored_at(1));
+
+        parent.chain_work = ChainWork::from_be_bytes(&[0xff; 32]).unwrap();
+        assert!(matches!(
+            extend(&parent, &header),
+            Err(TeranodeError::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sync_against_mock() {
+        let mock = MockTeranode::with_blocks(30);
+        let server = mock.serve().await.unwrap();
+        let client = TeranodeClient::connect(server.endpoint()).await.unwrap();
+        let store = HeaderStore::in_memory();
+
+        assert_eq!(store.sync(&client).await.unwrap(), 30);
+        let tip = store.tip().unwrap().unwrap();
+        assert_eq!(tip.hash(), mock.tip().hash());
+        assert_eq!(tip.chain_work.to_be_bytes()[..], mock.tip().meta.chain_work);
+
+        let locator = store.locator().unwrap();
+        assert_eq!(locator.len(), 14);
+        assert_eq!(locator[0], mock.tip().hash());
+
+        mock.mine_blocks(3);
+        assert_eq!(store.sync(&client).await.unwrap(), 3);
+        assert_eq!(store.tip().unwrap().unwrap().hash(), mock.tip().hash());
+        assert_eq!(store.sync(&client).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sync_then_analyze_fork() {
+        let mock = MockTeranode::with_blocks(20);
+        let branch = mock.fork(15, 2);
+        let server = mock.serve().await.unwrap();
+        let store = HeaderStore::in_memory();
+        let mut client = TeranodeClient::builder()
+            .blockchain_endpoint(server.endpoint())
+            .header_store(store.clone())
+            .connect()
+            .await
+            .unwrap();
+
+        assert_eq!(store.sync(&client).await.unwrap(), 20);
+
+        // Headers stored by the sync don't stand in for the node's metadata
+        let tip = client
+            .get_chain_tips()
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|tip| tip.hash == branch[1])
+            .unwrap();
+        let fork = client.analyze_fork(tip).await.unwrap();
+        let fork_point = mock.block_at(15).unwrap();
+        assert_eq!(fork.fork_point.hash(), fork_point.hash());
+        assert_eq!(fork.fork_point.meta.id, fork_point.meta.id);
+        assert_eq!(fork.fork_height(), 15);
+        assert_eq!(fork.branch_len(), 2);
+
+        // Looking up the fork leaves the synced chain alone
+        assert_eq!(store.tip().unwrap().unwrap().hash(), mock.tip().hash());
+        assert_eq!(store.sync(&client).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_locator_rpcs_against_mock() {
+        let mock = MockTeranode::with_blocks(12);
+        let branch = mock.fork(8, 2);
+        let server = mock.serve().await.unwrap();
+        let mut client = TeranodeClient::connect(server.endpoint()).await.unwrap();
+        let hash_at = |height| mock.block_at(height).unwrap().hash();
+
+        let locator = client.get_block_locator(&branch[1], 10).await.unwrap();
+        assert_eq!(locator.len(), locator_heights(10).len());
+        assert_eq!(
+            locator[..2],
+            branch[..].iter().rev().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(locator.last(), Some(&hash_at(0)));
+
+        // The fork's locator meets the active chain at the fork point
+        let tip = mock.tip().hash();
+        let common = client
+            .get_latest_block_header_from_block_locator(&tip, &locator)
+            .await
+            .unwrap();
+        assert_eq!(common.meta.height, 8);
+
+        let stop = hash_at(11);
+        let headers = client
+            .locate_block_headers(&[hash_at(8)], Some(&stop), 0)
+            .await
+            .unwrap();
+        let hashes: Vec<BlockHash> = headers.iter().map(|h| h.hash()).collect();
+        assert_eq!(hashes, vec![hash_at(9), hash_at(10), stop]);
+
+        let best = client.get_best_height_and_time().await.unwrap();
+        assert_eq!(best.height, 12);
+        assert_eq!(
+            best.median_time,
+            mock.block_at(7).unwrap().block.header.timestamp()
+        );
+    }
+}
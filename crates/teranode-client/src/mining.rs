@@ -0,0 +1,143 @@
+//! Mining support
+//!
+//! Typed results for the calls a miner needs beyond block templates: the
+//! suitable block used by the difficulty adjustment, and the difficulty the
+//! next block must meet. [`CandidateCheck`] compares a mining candidate's
+//! `nBits` with what the node expects, to catch stale or miscomputed
+//! candidates before hashing starts.
+
+use crate::error::{Result, TeranodeError};
+use crate::header::decode_block_hash;
+use crate::proto::model::{MiningCandidate, SuitableBlock as ProtoSuitableBlock};
+use crate::work::{ChainWork, Target};
+use bitcoinsv::bitcoin::BlockHash;
+
+/// The block chosen as a difficulty-adjustment reference point
+///
+/// Teranode picks the median by timestamp of a block and its two parents, to
+/// damp the effect of a single block with a skewed timestamp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuitableBlock {
+    /// Hash of the block
+    pub hash: BlockHash,
+
+    /// Height of the block
+    pub height: u32,
+
+    /// Compact difficulty bits of the block
+    pub bits: u32,
+
+    /// Block timestamp
+    pub time: u32,
+
+    /// Accumulated chain work up to and including the block
+    pub chain_work: ChainWork,
+}
+
+impl SuitableBlock {
+    /// The proof-of-work target the block's bits encode
+    pub fn target(&self) -> Result<Target> {
+        Target::from_compact(self.bits)
+    }
+}
+
+impl TryFrom<ProtoSuitableBlock> for SuitableBlock {
+    type Error = TeranodeError;
+
+    fn try_from(block: ProtoSuitableBlock) -> Result<Self> {
+        let target = Target::from_compact_bytes(&block.n_bits)?;
+        Ok(Self {
+            hash: decode_block_hash(&block.hash)?,
+            height: block.height,
+            bits: target.to_compact(),
+            time: block.time,
+            chain_work: ChainWork::from_be_bytes(&block.chain_work)?,
+        })
+    }
+}
+
+/// A mining candidate's difficulty compared with the node's next-work-required value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CandidateCheck {
+    /// Hash of the block the candidate builds on
+    pub previous_hash: BlockHash,
+
+    /// Target the candidate's `nBits` encode
+    pub candidate: Target,
+
+    /// Target the node requires for a block on top of `previous_hash`
+    pub required: Target,
+}
+
+impl CandidateCheck {
+    /// Whether the candidate uses the required difficulty
+    pub fn is_valid(&self) -> bool {
+        self.candidate == self.required
+    }
+}
+
+/// Hash of the block a candidate builds on, and the target its `nBits` encode
+pub(crate) fn decode_candidate(candidate: &MiningCandidate) -> Result<(BlockHash, Target)> {
+    Ok((
+        decode_block_hash(&candidate.previous_hash)?,
+        Target::from_compact_bytes(&candidate.n_bits)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MockTeranode, MOCK_BITS, MOCK_BLOCK_INTERVAL};
+    use crate::work::DIFFICULTY_1_BITS;
+    use crate::TeranodeClient;
+
+    #[test]
+    fn test_suitable_block_from_proto() {
+        let block = ProtoSuitableBlock {
+            hash: vec![0x11; 32],
+            height: 7,
+            n_bits: DIFFICULTY_1_BITS.to_le_bytes().to_vec(),
+            time: 1_231_006_505,
+            chain_work: vec![0x01, 0x00],
+        };
+
+        let suitable = SuitableBlock::try_from(block.clone()).unwrap();
+        assert_eq!(suitable.bits, DIFFICULTY_1_BITS);
+        assert_eq!(suitable.target().unwrap(), Target::difficulty_1());
+        assert_eq!(suitable.chain_work.to_string(), "256");
+
+        let bad = ProtoSuitableBlock {
+            n_bits: vec![0xff; 3],
+            ..block
+        };
+        assert!(SuitableBlock::try_from(bad).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mining_against_mock() {
+        let mock = MockTeranode::with_blocks(5);
+        let server = mock.serve().await.unwrap();
+        let mut client = TeranodeClient::connect(server.endpoint()).await.unwrap();
+        let tip = mock.tip();
+
+        let suitable = client.get_suitable_block(&tip.hash()).await.unwrap();
+        assert_eq!(suitable.height, 4);
+        assert_eq!(suitable.bits, MOCK_BITS);
+
+        let mut candidate = MiningCandidate {
+            previous_hash: tip.hash().raw.to_vec(),
+            n_bits: MOCK_BITS.to_le_bytes().to_vec(),
+            time: tip.block.header.timestamp() + MOCK_BLOCK_INTERVAL,
+            height: 6,
+            ..Default::default()
+        };
+        let check = client.check_mining_candidate(&candidate).await.unwrap();
+        assert!(check.is_valid());
+        assert_eq!(check.previous_hash, tip.hash());
+
+        candidate.n_bits = DIFFICULTY_1_BITS.to_le_bytes().to_vec();
+        let check = client.check_mining_candidate(&candidate).await.unwrap();
+        assert!(!check.is_valid());
+        assert_eq!(check.required, Target::from_compact(MOCK_BITS).unwrap());
+    }
+}
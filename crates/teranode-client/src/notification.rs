@@ -0,0 +1,354 @@
+//! Blockchain notification subscriptions
+//!
+//! Wraps the server-streaming `Subscribe` RPC in a stream that decodes each
+//! notification and transparently re-subscribes when the gRPC stream drops.
+
+use crate::error::{Result, TeranodeError};
+use crate::interceptor::Transport;
+use crate::proto::blockchain_api::{
+    blockchain_api_client::BlockchainApiClient, Notification as ProtoNotification, SubscribeRequest,
+};
+use crate::proto::model::NotificationType;
+use crate::retry::clamp_delay;
+use bitcoinsv::bitcoin::{BlockHash, Hash};
+use futures::Stream;
+use std::collections::HashMap;
+use std::time::Duration;
+use tonic::codec::Streaming;
+use tracing::{debug, warn};
+
+/// A decoded blockchain notification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// Type of notification
+    pub notification_type: NotificationType,
+
+    /// Hash of the block or subtree the notification relates to
+    pub hash: BlockHash,
+
+    /// Base URL where the related data can be fetched
+    pub base_url: String,
+
+    /// Additional key-value metadata
+    pub metadata: HashMap<String, String>,
+}
+
+impl TryFrom<ProtoNotification> for Notification {
+    type Error = TeranodeError;
+
+    fn try_from(notification: ProtoNotification) -> Result<Self> {
+        let notification_type = NotificationType::try_from(notification.r#type).map_err(|_| {
+            TeranodeError::DecodeError(format!(
+                "Unknown notification type: {}",
+                notification.r#type
+            ))
+        })?;
+
+        let hash = match notification.hash.len() {
+            0 => Hash::ZERO,
+            len if len == Hash::SIZE as usize => Hash::from_slice(&notification.hash),
+            len => {
+                return Err(TeranodeError::DecodeError(format!(
+                    "Invalid notification hash length: {}",
+                    len
+                )))
+            }
+        };
+
+        Ok(Self {
+            notification_type,
+            hash,
+            base_url: notification.base_url,
+            metadata: notification
+                .metadata
+                .map(|m| m.metadata)
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// An item produced by a subscription stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionEvent {
+    /// A notification from the blockchain service
+    Notification(Notification),
+
+    /// The subscription was interrupted and has been re-established
+    ///
+    /// Notifications sent while the stream was down have been missed, so
+    /// consumers should resynchronise from the node's current state.
+    Gap {
+        /// Number of connection attempts it took to re-subscribe
+        attempts: u32,
+    },
+}
+
+/// Shortest delay between re-subscription attempts
+pub const MIN_RECONNECT_DELAY: Duration = Duration::from_millis(10);
+
+/// Backoff applied between re-subscription attempts
+#[derive(Debug, Clone)]
+pub struct ReconnectBackoff {
+    /// Delay before the first re-subscription attempt, at least
+    /// [`MIN_RECONNECT_DELAY`]
+    pub initial_delay: Duration,
+
+    /// Upper bound on the delay between attempts
+    pub max_delay: Duration,
+
+    /// Factor the delay is multiplied by after each failed attempt
+    pub multiplier: f64,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl ReconnectBackoff {
+    /// Delay to use after an attempt that waited `current`
+    ///
+    /// Never shorter than [`MIN_RECONNECT_DELAY`], whatever the fields hold,
+    /// so a dropped stream can't be re-subscribed in a tight loop.
+    fn next_delay(&self, current: Duration) -> Duration {
+        let secs = if current.is_zero() {
+            self.initial_delay.as_secs_f64()
+        } else {
+            current.as_secs_f64() * self.multiplier
+        };
+        let max = self.max_delay.max(MIN_RECONNECT_DELAY);
+        clamp_delay(secs, max).max(MIN_RECONNECT_DELAY)
+    }
+}
+
+/// State carried between items of a subscription stream
+struct Subscription {
+    client: BlockchainApiClient<Transport>,
+    source: String,
+    backoff: ReconnectBackoff,
+    stream: Option<Streaming<ProtoNotification>>,
+    delay: Duration,
+    attempts: u32,
+    interrupted: bool,
+}
+
+impl Subscription {
+    /// Wait for the next event, re-subscribing as often as necessary
+    async fn next_event(&mut self) -> SubscriptionEvent {
+        loop {
+            let Some(stream) = self.stream.as_mut() else {
+                if let Some(event) = self.resubscribe().await {
+                    return event;
+                }
+                continue;
+            };
+
+            match stream.message().await {
+                Ok(Some(notification)) => {
+                    // Only a stream that delivers something counts as healthy
+                    self.delay = Duration::ZERO;
+                    match Notification::try_from(notification) {
+                        Ok(n) if n.notification_type == NotificationType::Ping => {}
+                        Ok(n) => return SubscriptionEvent::Notification(n),
+                        Err(e) => warn!("Skipping undecodable notification: {:#}", e),
+                    }
+                }
+                Ok(None) => {
+                    warn!("Notification stream closed by server");
+                    self.interrupt();
+                }
+                Err(status) => {
+                    warn!("Notification stream failed: {}", status);
+                    self.interrupt();
+                }
+            }
+        }
+    }
+
+    /// Drop the stream and back off before re-subscribing
+    ///
+    /// The delay keeps growing across streams that end before delivering
+    /// anything, so a node that accepts and immediately drops subscriptions
+    /// doesn't cause a tight reconnect loop.
+    fn interrupt(&mut self) {
+        self.stream = None;
+        self.interrupted = true;
+        self.delay = self.backoff.next_delay(self.delay);
+    }
+
+    /// Attempt to (re-)establish the stream, returning a gap marker if it was interrupted
+    async fn resubscribe(&mut self) -> Option<SubscriptionEvent> {
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+        self.attempts += 1;
+
+        let request = SubscribeRequest {
+            source: self.source.clone(),
+        };
+        match self.client.subscribe(request).await {
+            Ok(response) => {
+                debug!("Subscribed to notifications as {}", self.source);
+                self.stream = Some(response.into_inner());
+                let attempts = std::mem::take(&mut self.attempts);
+                if std::mem::take(&mut self.interrupted) {
+                    Some(SubscriptionEvent::Gap { attempts })
+                } else {
+                    None
+                }
+            }
+            Err(status) => {
+                self.delay = self.backoff.next_delay(self.delay);
+                warn!(
+                    "Subscribe attempt {} failed: {}, retrying in {:?}",
+                    self.attempts, status, self.delay
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Create a never-ending stream of notifications for `source`
+pub(crate) fn subscription_stream(
+    client: BlockchainApiClient<Transport>,
+    source: String,
+    backoff: ReconnectBackoff,
+) -> impl Stream<Item = SubscriptionEvent> + Send + 'static {
+    let subscription = Subscription {
+        client,
+        source,
+        backoff,
+        stream: None,
+        delay: Duration::ZERO,
+        attempts: 0,
+        interrupted: false,
+    };
+
+    futures::stream::unfold(subscription, |mut subscription| async move {
+        let event = subscription.next_event().await;
+        Some((event, subscription))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::blockchain_api::NotificationMetadata;
+    use crate::testing::MockTeranode;
+    use crate::TeranodeClient;
+    use futures::StreamExt;
+
+    #[test]
+    fn test_notification_decode() {
+        let proto = ProtoNotification {
+            r#type: NotificationType::Block as i32,
+            hash: vec![0x11; 32],
+            base_url: "http://node:8090".to_string(),
+            metadata: Some(NotificationMetadata {
+                metadata: HashMap::from([("height".to_string(), "10".to_string())]),
+            }),
+        };
+
+        let notification = Notification::try_from(proto).unwrap();
+        assert_eq!(notification.notification_type, NotificationType::Block);
+        assert_eq!(notification.hash, Hash::from([0x11; 32]));
+        assert_eq!(notification.base_url, "http://node:8090");
+        assert_eq!(notification.metadata["height"], "10");
+    }
+
+    #[test]
+    fn test_notification_decode_invalid() {
+        let bad_type = ProtoNotification {
+            r#type: 99,
+            ..Default::default()
+        };
+        assert!(Notification::try_from(bad_type).is_err());
+
+        let bad_hash = ProtoNotification {
+            r#type: NotificationType::Subtree as i32,
+            hash: vec![0x11; 20],
+            ..Default::default()
+        };
+        assert!(Notification::try_from(bad_hash).is_err());
+    }
+
+    #[test]
+    fn test_backoff_delays() {
+        let backoff = ReconnectBackoff {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+            multiplier: 2.0,
+        };
+
+        let mut delay = Duration::ZERO;
+        let mut delays = Vec::new();
+        for _ in 0..5 {
+            delay = backoff.next_delay(delay);
+            delays.push(delay.as_secs());
+        }
+        assert_eq!(delays, vec![1, 2, 4, 5, 5]);
+    }
+
+    #[test]
+    fn test_backoff_bad_fields() {
+        let zero = ReconnectBackoff {
+            initial_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            multiplier: 2.0,
+        };
+        assert_eq!(zero.next_delay(Duration::ZERO), MIN_RECONNECT_DELAY);
+        assert_eq!(zero.next_delay(MIN_RECONNECT_DELAY), MIN_RECONNECT_DELAY);
+
+        let max_delay = Duration::from_secs(30);
+        for multiplier in [-2.0, f64::NAN, f64::INFINITY, f64::MAX] {
+            let backoff = ReconnectBackoff {
+                multiplier,
+                ..ReconnectBackoff::default()
+            };
+            let delay = backoff.next_delay(Duration::from_secs(1));
+            assert!((MIN_RECONNECT_DELAY..=max_delay).contains(&delay));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resubscribe_after_disconnect() {
+        let mock = MockTeranode::new();
+        let server = mock.serve().await.unwrap();
+        let mut client = TeranodeClient::connect(server.endpoint()).await.unwrap();
+        let backoff = ReconnectBackoff {
+            initial_delay: Duration::from_millis(10),
+            ..ReconnectBackoff::default()
+        };
+        let mut events = Box::pin(client.subscribe_with_backoff("test", backoff).unwrap());
+        let wait = Duration::from_secs(5);
+
+        // Keep announcing blocks until the subscription has been established
+        let mut established = false;
+        for _ in 0..50 {
+            mock.mine_blocks(1);
+            if let Ok(Some(event)) =
+                tokio::time::timeout(Duration::from_millis(100), events.next()).await
+            {
+                assert!(matches!(event, SubscriptionEvent::Notification(_)));
+                established = true;
+                break;
+            }
+        }
+        assert!(established, "subscription was never established");
+
+        mock.disconnect_subscribers();
+        let gap = tokio::time::timeout(wait, events.next()).await.unwrap();
+        assert_eq!(gap, Some(SubscriptionEvent::Gap { attempts: 1 }));
+
+        // The gap is only reported once the new stream is in place
+        let hash = mock.mine_blocks(1)[0];
+        let resumed = tokio::time::timeout(wait, events.next()).await.unwrap();
+        assert!(matches!(resumed, Some(SubscriptionEvent::Notification(ref n)) if n.hash == hash));
+    }
+}
@@ -0,0 +1,205 @@
+//! Paged streams over height ranges
+//!
+//! Large ranges are split into bounded batches so no single response runs
+//! into gRPC message-size limits. Several batches are fetched concurrently,
+//! but items are always yielded in height order.
+
+use crate::error::Result;
+use futures::{stream, Stream, StreamExt};
+use std::future::Future;
+use std::ops::{Bound, RangeBounds};
+
+/// How a height range is split into requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageOptions {
+    /// Maximum number of items fetched per request
+    pub batch_size: u32,
+
+    /// Maximum number of requests in flight at once
+    pub concurrency: usize,
+}
+
+impl PageOptions {
+    /// Defaults for header streams: headers are small, so use large batches
+    pub fn headers() -> Self {
+        Self {
+            batch_size: 1000,
+            concurrency: 4,
+        }
+    }
+
+    /// Defaults for block streams: blocks carry subtree hashes and the
+    /// coinbase, so keep batches small
+    pub fn blocks() -> Self {
+        Self {
+            batch_size: 50,
+            concurrency: 4,
+        }
+    }
+}
+
+/// Inclusive start and end heights of a range
+///
+/// An unbounded end is treated as "up to the tip".
+pub(crate) fn height_bounds(range: impl RangeBounds<u32>) -> Option<(u32, u32)> {
+    let start = match range.start_bound() {
+        Bound::Included(&h) => h,
+        Bound::Excluded(&h) => h.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&h) => h,
+        Bound::Excluded(&h) => h.checked_sub(1)?,
+        Bound::Unbounded => u32::MAX,
+    };
+    (start <= end).then_some((start, end))
+}
+
+/// Stream the items from `start` to `end`, fetching each batch with `fetch`
+///
+/// `fetch` receives the inclusive heights of a request and returns the items
+/// from the first height on. A short page is followed up with a request for
+/// the rest of the batch, since servers may cap the page size; an empty page
+/// means the tip has been reached, and the stream ends there. The stream also
+/// ends after yielding an error.
+pub(crate) fn paged_stream<T, F, Fut>(
+    bounds: Option<(u32, u32)>,
+    options: PageOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<T>> + Send + 'static
+where
+    T: Send + 'static,
+    F: Fn(u32, u32) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    let batch_size = options.batch_size.max(1);
+    let batches = stream::iter(bounds).flat_map(move |(start, end)| {
+        let heights = (start..=end).step_by(batch_size as usize);
+        stream::iter(
+            heights.map(move |first| (first, first.saturating_add(batch_size - 1).min(end))),
+        )
+    });
+
+    batches
+        .map(move |(first, last)| fetch_batch(fetch.clone(), first, last))
+        .buffered(options.concurrency.max(1))
+        .scan(false, |done, batch| {
+            if *done {
+                return futures::future::ready(None);
+            }
+            let items: Vec<Result<T>> = match batch {
+                Ok((items, complete)) => {
+                    *done = !complete;
+                    items.into_iter().map(Ok).collect()
+                }
+                Err(e) => {
+                    *done = true;
+                    vec![Err(e)]
+                }
+            };
+            futures::future::ready(Some(stream::iter(items)))
+        })
+        .flatten()
+}
+
+/// Fetch the items from `first` to `last`, however many pages that takes
+///
+/// Also returns whether the batch is complete, which it is not if an empty
+/// page showed that nothing exists past the items fetched so far.
+async fn fetch_batch<T, F, Fut>(fetch: F, first: u32, last: u32) -> Result<(Vec<T>, bool)>
+where
+    F: Fn(u32, u32) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    let mut items = Vec::new();
+    let mut next = first as u64;
+    while next <= last as u64 {
+        let page = fetch(next as u32, last).await?;
+        if page.is_empty() {
+            return Ok((items, false));
+        }
+        next += page.len() as u64;
+        items.extend(page);
+    }
+    Ok((items, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::serve_with_blocks;
+    use crate::TeranodeError;
+
+    #[test]
+    fn test_height_bounds() {
+        assert_eq!(height_bounds(0..10), Some((0, 9)));
+        assert_eq!(height_bounds(5..=5), Some((5, 5)));
+        assert_eq!(height_bounds(7..), Some((7, u32::MAX)));
+        assert_eq!(height_bounds(5..5), None);
+        assert_eq!(height_bounds(..0), None);
+    }
+
+    #[tokio::test]
+    async fn test_paged_stream_order_and_end() {
+        let options = PageOptions {
+            batch_size: 3,
+            concurrency: 4,
+        };
+
+        // Later batches complete first, but items still come out in order
+        let items: Vec<u32> = paged_stream(Some((0, 10)), options, |first, last| async move {
+            tokio::time::sleep(std::time::Duration::from_millis(20 - first as u64)).await;
+            Ok((first..=last).collect())
+        })
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+        assert_eq!(items, (0..=10).collect::<Vec<_>>());
+
+        // The chain ends at height 7
+        let items: Vec<u32> =
+            paged_stream(Some((2, u32::MAX)), options, |first, last| async move {
+                Ok((first..=last.min(7)).collect())
+            })
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+        assert_eq!(items, (2..=7).collect::<Vec<_>>());
+
+        // A server capping pages at 2 items doesn't truncate the stream
+        let items: Vec<u32> = paged_stream(Some((0, 20)), options, |first, last| async move {
+            Ok((first..=last.min(first + 1).min(15)).collect())
+        })
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+        assert_eq!(items, (0..=15).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_paged_streams_against_mock() {
+        let (mock, _server, client) = serve_with_blocks(25).await;
+        let options = PageOptions {
+            batch_size: 4,
+            concurrency: 3,
+        };
+
+        let headers: Vec<u32> = client
+            .headers_stream_with_options(3..=20, options)
+            .map(|h| h.unwrap().meta.height)
+            .collect()
+            .await;
+        assert_eq!(headers, (3..=20).collect::<Vec<_>>());
+
+        let blocks: Vec<u32> = client
+            .blocks_stream_with_options(10.., options)
+            .map(|b| b.unwrap().height)
+            .collect()
+            .await;
+        assert_eq!(blocks, (10..=25).collect::<Vec<_>>());
+
+        mock.fail_next(tonic::Status::internal("boom"));
+        let results: Vec<_> = client.headers_stream(..).collect().await;
+        assert!(matches!(results[..], [Err(TeranodeError::GrpcError(_))]));
+    }
+}
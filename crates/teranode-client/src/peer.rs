@@ -0,0 +1,247 @@
+//! Peer service types
+
+use crate::error::{Result, TeranodeError};
+use libp2p::multiaddr::Protocol;
+use libp2p::{Multiaddr, PeerId};
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// An IP address or subnet that can be banned
+///
+/// A single address is represented as a subnet with a full-length prefix
+/// (/32 for IPv4, /128 for IPv6) and is displayed without the prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpSubnet {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpSubnet {
+    /// Create a subnet from a base address and prefix length
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Result<Self> {
+        let max = Self::max_prefix_len(&addr);
+        if prefix_len > max {
+            return Err(invalid(format!(
+                "Invalid prefix length /{} for {}",
+                prefix_len, addr
+            )));
+        }
+        Ok(Self { addr, prefix_len })
+    }
+
+    /// Base address of the subnet
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Prefix length in bits
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Whether this represents a single address rather than a range
+    pub fn is_single_address(&self) -> bool {
+        self.prefix_len == Self::max_prefix_len(&self.addr)
+    }
+
+    fn max_prefix_len(addr: &IpAddr) -> u8 {
+        match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        }
+    }
+}
+
+impl From<IpAddr> for IpSubnet {
+    fn from(addr: IpAddr) -> Self {
+        Self {
+            addr,
+            prefix_len: Self::max_prefix_len(&addr),
+        }
+    }
+}
+
+impl FromStr for IpSubnet {
+    type Err = TeranodeError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once('/') {
+            Some((addr, prefix)) => {
+                let addr: IpAddr = addr
+                    .parse()
+                    .map_err(|_| invalid(format!("Invalid IP address: {}", addr)))?;
+                let prefix_len: u8 = prefix
+                    .parse()
+                    .map_err(|_| invalid(format!("Invalid prefix length: {}", prefix)))?;
+                Self::new(addr, prefix_len)
+            }
+            None => s
+                .parse::<IpAddr>()
+                .map(Self::from)
+                .map_err(|_| invalid(format!("Invalid IP address: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for IpSubnet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_single_address() {
+            write!(f, "{}", self.addr)
+        } else {
+            write!(f, "{}/{}", self.addr, self.prefix_len)
+        }
+    }
+}
+
+/// Check that `addr` is a dialable peer address and return its peer ID
+///
+/// Teranode expects addresses of the form `/ip4/127.0.0.1/tcp/9005/p2p/12D3KooW...`:
+/// a network address, a transport and a trailing `/p2p` peer ID.
+pub fn validate_peer_multiaddr(addr: &Multiaddr) -> Result<PeerId> {
+    let mut has_host = false;
+    let mut has_transport = false;
+
+    for protocol in addr.iter() {
+        match protocol {
+            Protocol::Ip4(_)
+            | Protocol::Ip6(_)
+            | Protocol::Dns(_)
+            | Protocol::Dns4(_)
+            | Protocol::Dns6(_) => has_host = true,
+            Protocol::Tcp(_) | Protocol::Udp(_) => has_transport = true,
+            _ => {}
+        }
+    }
+
+    if !has_host {
+        return Err(invalid(format!(
+            "Peer address {} has no IP or DNS component",
+            addr
+        )));
+    }
+    if !has_transport {
+        return Err(invalid(format!(
+            "Peer address {} has no TCP or UDP port",
+            addr
+        )));
+    }
+
+    match addr.iter().last() {
+        Some(Protocol::P2p(peer_id)) => Ok(peer_id),
+        _ => Err(invalid(format!(
+            "Peer address {} does not end with a /p2p peer ID",
+            addr
+        ))),
+    }
+}
+
+fn invalid(message: String) -> TeranodeError {
+    TeranodeError::InvalidArgument(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::p2p_api::Peer;
+    use crate::testing::serve_with_blocks;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_subnet_parse() {
+        let single: IpSubnet = "10.0.0.1".parse().unwrap();
+        assert!(single.is_single_address());
+        assert_eq!(single.to_string(), "10.0.0.1");
+
+        let range: IpSubnet = "10.0.0.0/24".parse().unwrap();
+        assert_eq!(range.prefix_len(), 24);
+        assert_eq!(range.to_string(), "10.0.0.0/24");
+
+        let v6: IpSubnet = "2001:db8::/32".parse().unwrap();
+        assert_eq!(v6.to_string(), "2001:db8::/32");
+        assert_eq!("::1/128".parse::<IpSubnet>().unwrap().to_string(), "::1");
+    }
+
+    #[test]
+    fn test_subnet_parse_invalid() {
+        assert!("10.0.0.0/33".parse::<IpSubnet>().is_err());
+        assert!("10.0.0/8".parse::<IpSubnet>().is_err());
+        assert!("node.example.com".parse::<IpSubnet>().is_err());
+    }
+
+    #[test]
+    fn test_validate_peer_multiaddr() {
+        let peer_id = PeerId::random();
+
+        let addr: Multiaddr = format!("/ip4/127.0.0.1/tcp/9005/p2p/{}", peer_id)
+            .parse()
+            .unwrap();
+        assert_eq!(validate_peer_multiaddr(&addr).unwrap(), peer_id);
+
+        let dns: Multiaddr = format!("/dns4/node.example.com/tcp/9005/p2p/{}", peer_id)
+            .parse()
+            .unwrap();
+        assert!(validate_peer_multiaddr(&dns).is_ok());
+
+        let no_peer: Multiaddr = "/ip4/127.0.0.1/tcp/9005".parse().unwrap();
+        assert!(validate_peer_multiaddr(&no_peer).is_err());
+
+        let no_port: Multiaddr = format!("/ip4/127.0.0.1/p2p/{}", peer_id).parse().unwrap();
+        assert!(validate_peer_multiaddr(&no_port).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ban_management_against_mock() {
+        let (mock, _server, mut client) = serve_with_blocks(0).await;
+        let until = SystemTime::now() + Duration::from_secs(60);
+        let single: IpSubnet = "10.0.0.1".parse().unwrap();
+        let range: IpSubnet = "10.1.0.0/16".parse().unwrap();
+
+        client.ban_peer(single, until).await.unwrap();
+        client.ban_peer(range, until).await.unwrap();
+        assert_eq!(client.list_banned().await.unwrap(), vec![single, range]);
+
+        client.unban_peer(single).await.unwrap();
+        assert!(!client.is_banned(single).await.unwrap());
+        let err = client.unban_peer(single).await.unwrap_err();
+        assert!(matches!(err, TeranodeError::RequestRejected(_)));
+
+        client.clear_banned().await.unwrap();
+        assert!(client.list_banned().await.unwrap().is_empty());
+        assert!(mock.banned().is_empty());
+
+        let peer_id = PeerId::random();
+        mock.add_peer(Peer {
+            id: peer_id.to_string(),
+            ..Default::default()
+        });
+        client.add_ban_score(&peer_id, "spam").await.unwrap();
+        let err = client
+            .add_ban_score(&PeerId::random(), "spam")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TeranodeError::RequestRejected(_)));
+    }
+
+    #[tokio::test]
+    async fn test_peer_connections_against_mock() {
+        let (_mock, _server, mut client) = serve_with_blocks(0).await;
+        let peer_id = PeerId::random();
+        let addr: Multiaddr = format!("/ip4/127.0.0.1/tcp/9005/p2p/{}", peer_id)
+            .parse()
+            .unwrap();
+
+        client.connect_peer(&addr).await.unwrap();
+        let peers = client.get_peers().await.unwrap().peers;
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].id, peer_id.to_string());
+
+        let err = client.connect_peer(&addr).await.unwrap_err();
+        assert!(matches!(err, TeranodeError::RequestRejected(ref m) if m.contains("already")));
+
+        client.disconnect_peer(&peer_id).await.unwrap();
+        assert!(client.get_peers().await.unwrap().peers.is_empty());
+        let err = client.disconnect_peer(&peer_id).await.unwrap_err();
+        assert!(matches!(err, TeranodeError::RequestRejected(_)));
+    }
+}
@@ -0,0 +1,455 @@
+//! Client pool over several Teranode instances
+//!
+//! [`TeranodePool`] holds one [`TeranodeClient`] per node. Routed calls go to
+//! the first available node and fail over to the next when a node is
+//! unreachable or overloaded; a node that fails is skipped for a cooldown
+//! period. "Ask all" calls query every node at once, and [`Consensus`]
+//! groups their answers to show which nodes agree and which are outliers.
+
+use crate::client::TeranodeClient;
+use crate::error::{Result, TeranodeError};
+use crate::header::HeaderWithMeta;
+use futures::future::join_all;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use tonic::Code;
+use tracing::warn;
+
+/// How long a failed node is skipped by routed calls, unless set with
+/// [`TeranodePool::with_cooldown`]
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Availability of one node in a pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeStatus {
+    /// Name the node was added under
+    pub name: String,
+
+    /// Whether routed calls currently try the node in its normal turn
+    pub available: bool,
+
+    /// Number of failed calls since the node last answered
+    pub consecutive_failures: u32,
+}
+
+#[derive(Debug, Default)]
+struct NodeState {
+    consecutive_failures: u32,
+    down_until: Option<Instant>,
+}
+
+struct Node {
+    name: String,
+    client: TeranodeClient,
+    state: Mutex<NodeState>,
+}
+
+impl Node {
+    fn state(&self) -> MutexGuard<'_, NodeState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// When the node's cooldown ends, if it is cooling down at `now`
+    fn down_until(&self, now: Instant) -> Option<Instant> {
+        self.state().down_until.filter(|until| *until > now)
+    }
+
+    /// Record the outcome of a call; only node failures count against it
+    fn record<T>(&self, result: &Result<T>, cooldown: Duration) {
+        let mut state = self.state();
+        match result {
+            Err(e) if is_node_failure(e) => {
+                state.consecutive_failures += 1;
+                state.down_until = Some(Instant::now() + cooldown);
+                warn!("Teranode node {} failed: {}", self.name, e);
+            }
+            _ => *state = NodeState::default(),
+        }
+    }
+}
+
+/// Whether an error means the node itself is unusable, rather than that the
+/// request was answered with an error
+fn is_node_failure(error: &TeranodeError) -> bool {
+    match error {
+        TeranodeError::ConnectionError(_) | TeranodeError::Timeout(_) => true,
+        TeranodeError::GrpcError(status) => matches!(
+            status.code(),
+            Code::Unavailable
+                | Code::DeadlineExceeded
+                | Code::Aborted
+                | Code::ResourceExhausted
+                | Code::Internal
+                | Code::Unknown
+        ),
+        _ => false,
+    }
+}
+
+/// A set of Teranode clients used as one
+///
+/// Nodes are tried in the order they were added, so the first node acts as
+/// the primary. Cloning is cheap; clones share the clients and the record of
+/// which nodes have failed.
+///
+/// ```no_run
+/// # async fn example() -> teranode_client::Result<()> {
+/// use teranode_client::{TeranodeClient, TeranodePool};
+///
+/// let pool = TeranodePool::new([
+///     ("a", TeranodeClient::connect("http://10.0.0.1:8087").await?),
+///     ("b", TeranodeClient::connect("http://10.0.0.2:8087").await?),
+/// ]);
+///
+/// let tips = pool.call(|mut client| async move { client.get_chain_tips().await }).await?;
+///
+/// let best = pool.get_best_header_consensus().await;
+/// for (node, header) in &best.outliers {
+///     println!("{} is at {} (height {})", node, header.hash(), header.meta.height);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TeranodePool {
+    nodes: Arc<Vec<Node>>,
+    cooldown: Duration,
+}
+
+impl TeranodePool {
+    /// Create a pool from named clients, in order of preference
+    pub fn new<N: Into<String>>(nodes: impl IntoIterator<Item = (N, TeranodeClient)>) -> Self {
+        let nodes = nodes
+            .into_iter()
+            .map(|(name, client)| Node {
+                name: name.into(),
+                client,
+                state: Mutex::default(),
+            })
+            .collect();
+
+        Self {
+            nodes: Arc::new(nodes),
+            cooldown: DEFAULT_COOLDOWN,
+        }
+    }
+
+    /// Create a pool of lazily connected clients, one per node
+    ///
+    /// Each node is named after its blockchain endpoint. See
+    /// [`TeranodeClient::connect_lazy`]; use [`new`](Self::new) with clients
+    /// from [`TeranodeClient::builder`] for other settings.
+    ///
+    /// # Arguments
+    /// * `endpoints` - Blockchain and optional peer service endpoint of each node
+    pub fn connect_lazy<B, P>(endpoints: impl IntoIterator<Item = (B, Option<P>)>) -> Result<Self>
+    where
+        B: AsRef<str>,
+        P: AsRef<str>,
+    {
+        let nodes = endpoints
+            .into_iter()
+            .map(|(blockchain, peer)| {
+                let client = TeranodeClient::connect_lazy(Some(blockchain.as_ref()), peer)?;
+                Ok((blockchain.as_ref().to_string(), client))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(nodes))
+    }
+
+    /// Set how long a failed node is skipped by routed calls
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Number of nodes in the pool
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the pool has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Availability of each node, in pool order
+    pub fn status(&self) -> Vec<NodeStatus> {
+        let now = Instant::now();
+        self.nodes
+            .iter()
+            .map(|node| NodeStatus {
+                name: node.name.clone(),
+                available: node.down_until(now).is_none(),
+                consecutive_failures: node.state().consecutive_failures,
+            })
+            .collect()
+    }
+
+    /// Order in which routed calls try the nodes: available nodes in pool
+    /// order, then cooling-down nodes by when their cooldown ends
+    fn order(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let mut order: Vec<(Option<Instant>, usize)> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.down_until(now), i))
+            .collect();
+        order.sort();
+        order.into_iter().map(|(_, i)| i).collect()
+    }
+
+    /// Run a call on the first node that can answer it
+    ///
+    /// The call moves on to the next node when a node is unreachable, times
+    /// out or reports itself unavailable. Any other error is the node's answer
+    /// and is returned as is. Nodes that are cooling down are still tried,
+    /// last, so a call only fails when every node has failed.
+    ///
+    /// A mutating call is only repeated on another node when the first node
+    /// could not be reached or did not answer, but as with retries, a request
+    /// that timed out may still have been applied.
+    ///
+    /// # Arguments
+    /// * `call` - Runs the request on a node's client
+    pub async fn call<T, F, Fut>(&self, call: F) -> Result<T>
+    where
+        F: Fn(TeranodeClient) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut last_error = None;
+        for i in self.order() {
+            let node = &self.nodes[i];
+            let result = call(node.client.clone()).await;
+            node.record(&result, self.cooldown);
+            match result {
+                Err(e) if is_node_failure(&e) => last_error = Some(e),
+                result => return result,
+            }
+        }
+
+        Err(last_error
+            .unwrap_or_else(|| TeranodeError::ConfigError("Node pool is empty".to_string())))
+    }
+
+    /// Run a call on every node at once
+    ///
+    /// # Returns
+    /// Each node's name and result, in pool order
+    pub async fn call_all<T, F, Fut>(&self, call: F) -> Vec<(String, Result<T>)>
+    where
+        F: Fn(TeranodeClient) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let results = join_all(self.nodes.iter().map(|node| call(node.client.clone()))).await;
+        self.nodes
+            .iter()
+            .zip(results)
+            .map(|(node, result)| {
+                node.record(&result, self.cooldown);
+                (node.name.clone(), result)
+            })
+            .collect()
+    }
+
+    /// Get the best block header from every node, grouped by block hash
+    pub async fn get_best_header_consensus(&self) -> Consensus<HeaderWithMeta> {
+        let results = self
+            .call_all(|mut client| async move {
+                HeaderWithMeta::try_from(client.get_best_block_header().await?)
+            })
+            .await;
+        Consensus::from_results(results, HeaderWithMeta::hash)
+    }
+}
+
+/// Answers from every node in a pool, grouped by agreement
+#[derive(Debug)]
+pub struct Consensus<T> {
+    /// The most common answer, `None` if no node answered
+    ///
+    /// Ties go to the answer of the node earliest in the pool.
+    pub answer: Option<T>,
+
+    /// Nodes that gave the most common answer
+    pub agreeing: Vec<String>,
+
+    /// Nodes that gave a different answer, with their answers
+    pub outliers: Vec<(String, T)>,
+
+    /// Nodes that failed to answer
+    pub failed: Vec<(String, TeranodeError)>,
+}
+
+impl<T> Consensus<T> {
+    /// Group results from [`TeranodePool::call_all`]
+    ///
+    /// # Arguments
+    /// * `results` - Each node's name and result
+    /// * `key` - The part of an answer that must match for two nodes to agree
+    pub fn from_results<K: PartialEq>(
+        results: Vec<(String, Result<T>)>,
+        key: impl Fn(&T) -> K,
+    ) -> Self {
+        let mut answers = Vec::new();
+        let mut failed = Vec::new();
+        for (name, result) in results {
+            match result {
+                Ok(answer) => answers.push((name, answer)),
+                Err(e) => failed.push((name, e)),
+            }
+        }
+
+        // Count agreement with the first answer of each group
+        let keys: Vec<K> = answers.iter().map(|(_, answer)| key(answer)).collect();
+        let winner = (0..keys.len())
+            .filter(|&i| keys[..i].iter().all(|k| *k != keys[i]))
+            .map(|i| (keys.iter().filter(|k| **k == keys[i]).count(), i))
+            .max_by_key(|&(count, i)| (count, std::cmp::Reverse(i)))
+            .map(|(_, i)| i);
+
+        let mut consensus = Self {
+            answer: None,
+            agreeing: Vec::new(),
+            outliers: Vec::new(),
+            failed,
+        };
+        for (i, (name, answer)) in answers.into_iter().enumerate() {
+            match winner {
+                Some(w) if keys[i] == keys[w] => {
+                    consensus.agreeing.push(name);
+                    if consensus.answer.is_none() {
+                        consensus.answer = Some(answer);
+                    }
+                }
+                _ => consensus.outliers.push((name, answer)),
+            }
+        }
+        consensus
+    }
+
+    /// Number of nodes asked
+    pub fn node_count(&self) -> usize {
+        self.agreeing.len() + self.outliers.len() + self.failed.len()
+    }
+
+    /// Whether more than half of the nodes asked gave the answer
+    pub fn has_majority(&self) -> bool {
+        self.agreeing.len() * 2 > self.node_count()
+    }
+
+    /// Whether every node answered and all gave the same answer
+    pub fn is_unanimous(&self) -> bool {
+        !self.agreeing.is_empty() && self.outliers.is_empty() && self.failed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::retry::RetryPolicy;
+    use crate::testing::MockTeranode;
+
+    #[test]
+    fn test_consensus_from_results() {
+        let ok = |name: &str, value: u32| (name.to_string(), Ok(value));
+        let results = vec![
+            ok("a", 1),
+            ok("b", 2),
+            ("c".to_string(), Err(TeranodeError::Timeout(Duration::ZERO))),
+            ok("d", 2),
+        ];
+
+        let consensus = Consensus::from_results(results, |v| *v);
+        assert_eq!(consensus.answer, Some(2));
+        assert_eq!(consensus.agreeing, vec!["b", "d"]);
+        assert_eq!(consensus.outliers, vec![("a".to_string(), 1)]);
+        assert_eq!(consensus.failed.len(), 1);
+        assert!(!consensus.has_majority());
+        assert!(!consensus.is_unanimous());
+
+        // Ties go to the earliest node
+        let consensus = Consensus::from_results(vec![ok("a", 1), ok("b", 2)], |v| *v);
+        assert_eq!(consensus.answer, Some(1));
+
+        let consensus = Consensus::<u32>::from_results(Vec::new(), |v| *v);
+        assert!(consensus.answer.is_none());
+        assert!(!consensus.has_majority());
+    }
+
+    /// A client for a node that is not running
+    async fn dead_client() -> TeranodeClient {
+        let server = MockTeranode::new().serve().await.unwrap();
+        let endpoint = server.endpoint();
+        server.shutdown().await;
+        TeranodeClient::connect_lazy(Some(endpoint), None::<&str>)
+            .unwrap()
+            .with_retry_policy(RetryPolicy::none())
+    }
+
+    #[tokio::test]
+    async fn test_pool_failover() {
+        let mock = MockTeranode::with_blocks(2);
+        let server = mock.serve().await.unwrap();
+        let live = TeranodeClient::connect(server.endpoint()).await.unwrap();
+        let pool = TeranodePool::new([("dead", dead_client().await), ("live", live)]);
+
+        let tip = pool
+            .call(|mut client| async move { client.get_best_block_header().await })
+            .await
+            .unwrap();
+        assert_eq!(tip.height, 2);
+
+        let status = pool.status();
+        assert!(!status[0].available);
+        assert_eq!(status[0].consecutive_failures, 1);
+        assert!(status[1].available);
+
+        // The failed node is now tried last, and answers are not failed over
+        mock.fail_next(tonic::Status::not_found("gone"));
+        let result = pool
+            .call(|mut client| async move { client.get_best_block_header().await })
+            .await;
+        assert!(matches!(result, Err(TeranodeError::NotFound(_))));
+        assert_eq!(pool.status()[0].consecutive_failures, 1);
+
+        let empty = TeranodePool::new(Vec::<(String, TeranodeClient)>::new());
+        let result = empty
+            .call(|mut client| async move { client.get_best_block_header().await })
+            .await;
+        assert!(matches!(result, Err(TeranodeError::ConfigError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_best_header_consensus() {
+        let mocks = [
+            MockTeranode::with_blocks(3),
+            MockTeranode::with_blocks(3),
+            MockTeranode::with_blocks(4),
+        ];
+        let mut servers = Vec::new();
+        let mut nodes = Vec::new();
+        for (i, mock) in mocks.iter().enumerate() {
+            let server = mock.serve().await.unwrap();
+            let client = TeranodeClient::connect(server.endpoint()).await.unwrap();
+            nodes.push((format!("node{}", i), client));
+            servers.push(server);
+        }
+        nodes.push(("dead".to_string(), dead_client().await));
+        let pool = TeranodePool::new(nodes);
+
+        let consensus = pool.get_best_header_consensus().await;
+        assert_eq!(
+            consensus.answer.as_ref().unwrap().hash(),
+            mocks[0].tip().hash()
+        );
+        assert_eq!(consensus.agreeing, vec!["node0", "node1"]);
+        assert_eq!(consensus.outliers.len(), 1);
+        assert_eq!(consensus.outliers[0].1.meta.height, 4);
+        assert_eq!(consensus.failed[0].0, "dead");
+        assert!(!consensus.has_majority());
+        assert!(!pool.status()[3].available);
+    }
+}
@@ -0,0 +1,5314 @@
+// This file is @generated by prost-build.
+/// HealthResponse represents the health status of the blockchain service.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HealthResponse {
+    /// Overall health status
+    #[prost(bool, tag = "1")]
+    pub ok: bool,
+    /// Detailed health information
+    #[prost(string, tag = "2")]
+    pub details: ::prost::alloc::string::String,
+    /// Timestamp of the health check
+    #[prost(message, optional, tag = "3")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// AddBlockRequest contains data for adding a new block to the blockchain.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddBlockRequest {
+    /// Block header
+    #[prost(bytes = "vec", tag = "1")]
+    pub header: ::prost::alloc::vec::Vec<u8>,
+    /// Merkle tree hashes
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub subtree_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// Coinbase transaction
+    #[prost(bytes = "vec", tag = "3")]
+    pub coinbase_tx: ::prost::alloc::vec::Vec<u8>,
+    /// Number of transactions
+    #[prost(uint64, tag = "4")]
+    pub transaction_count: u64,
+    /// Block size
+    #[prost(uint64, tag = "5")]
+    pub size_in_bytes: u64,
+    /// External block flag
+    #[prost(bool, tag = "6")]
+    pub external: bool,
+    /// Peer identifier
+    #[prost(string, tag = "7")]
+    pub peer_id: ::prost::alloc::string::String,
+    /// Option to mark block as mined
+    #[prost(bool, tag = "8")]
+    pub option_mined_set: bool,
+    /// Option to mark subtrees as set
+    #[prost(bool, tag = "9")]
+    pub option_subtrees_set: bool,
+    /// Option to invalidate block when adding
+    #[prost(bool, tag = "10")]
+    pub option_invalid: bool,
+    /// Optional block ID
+    #[prost(uint64, tag = "11")]
+    pub option_id: u64,
+}
+/// GetBlockRequest represents a request to retrieve a block by its hash.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockRequest {
+    /// Hash of the block to retrieve
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlocksRequest represents a request to retrieve multiple blocks.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlocksRequest {
+    /// Starting block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// Number of blocks to retrieve
+    #[prost(uint32, tag = "2")]
+    pub count: u32,
+}
+/// GetBlocksResponse contains multiple serialized blocks.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlocksResponse {
+    /// List of serialized blocks
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub blocks: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// GetBlockByHeightRequest represents a request to retrieve a block at a specific height.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetBlockByHeightRequest {
+    /// Block height to retrieve
+    #[prost(uint32, tag = "1")]
+    pub height: u32,
+}
+/// GetBlockByIDRequest represents a request to retrieve a block by its ID.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetBlockByIdRequest {
+    /// Block ID to retrieve
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+}
+/// GetNextBlockIDResponse represents the response containing the next available block ID.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetNextBlockIdResponse {
+    /// Next available block ID
+    #[prost(uint64, tag = "1")]
+    pub next_block_id: u64,
+}
+/// GetBlockInChainByHeightHashRequest represents a request to retrieve a block by height in a specific chain.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockInChainByHeightHashRequest {
+    /// Target block height
+    #[prost(uint32, tag = "1")]
+    pub height: u32,
+    /// Starting block hash defining the chain
+    #[prost(bytes = "vec", tag = "2")]
+    pub start_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlockResponse contains detailed block information.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockResponse {
+    /// Block header bytes
+    #[prost(bytes = "vec", tag = "1")]
+    pub header: ::prost::alloc::vec::Vec<u8>,
+    /// Block height
+    #[prost(uint32, tag = "2")]
+    pub height: u32,
+    /// Coinbase transaction bytes
+    #[prost(bytes = "vec", tag = "3")]
+    pub coinbase_tx: ::prost::alloc::vec::Vec<u8>,
+    /// Total number of transactions
+    #[prost(uint64, tag = "4")]
+    pub transaction_count: u64,
+    /// Merkle tree subtree hashes
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub subtree_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// Total block size in bytes
+    #[prost(uint64, tag = "6")]
+    pub size_in_bytes: u64,
+    /// Block identifier
+    #[prost(uint32, tag = "7")]
+    pub id: u32,
+}
+/// GetFullBlockResponse contains a complete serialized block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFullBlockResponse {
+    /// Complete serialized block data
+    #[prost(bytes = "vec", tag = "1")]
+    pub full_block_bytes: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlockGraphDataRequest specifies parameters for retrieving blockchain visualization data.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetBlockGraphDataRequest {
+    /// Time period in milliseconds
+    #[prost(uint64, tag = "1")]
+    pub period_millis: u64,
+}
+/// GetBlockExistsResponse indicates whether a block exists.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetBlockExistsResponse {
+    /// True if the block exists
+    #[prost(bool, tag = "1")]
+    pub exists: bool,
+}
+/// GetMedianTimeRequest requests median time calculation for a block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMedianTimeRequest {
+    /// Hash of the block
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlockHeadersRequest requests multiple block headers.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersRequest {
+    /// Starting block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub start_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Number of headers to retrieve
+    #[prost(uint64, tag = "2")]
+    pub number_of_headers: u64,
+}
+/// swagger:model GetBlockHeadersToCommonAncestorRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersToCommonAncestorRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub target_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub block_locator_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "3")]
+    pub max_headers: u32,
+}
+/// swagger:model GetBlockHeadersFromCommonAncestor
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersFromCommonAncestorRequest {
+    /// Target block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub target_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Block locator hashes
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub block_locator_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// Maximum number of headers to retrieve
+    #[prost(uint32, tag = "3")]
+    pub max_headers: u32,
+}
+/// GetBlockHeadersResponse contains multiple block headers and their metadata.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersResponse {
+    /// List of serialized block headers
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub block_headers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// List of serialized metadata
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub metas: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// GetBlockHeadersFromTillRequest requests headers between two blocks.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersFromTillRequest {
+    /// Starting block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub start_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Ending block hash
+    #[prost(bytes = "vec", tag = "2")]
+    pub end_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlockHeadersFromHeightRequest requests headers starting from a height.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersFromHeightRequest {
+    /// Starting height
+    #[prost(uint32, tag = "1")]
+    pub start_height: u32,
+    /// Maximum number of headers to retrieve
+    #[prost(uint32, tag = "2")]
+    pub limit: u32,
+}
+/// GetBlockHeadersFromHeightResponse contains headers and metadata from a height.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersFromHeightResponse {
+    /// List of serialized block headers
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub block_headers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// List of serialized metadata
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub metas: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// GetBlockHeadersByHeightRequest requests headers between two heights.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersByHeightRequest {
+    /// Starting height
+    #[prost(uint32, tag = "1")]
+    pub start_height: u32,
+    /// Ending height
+    #[prost(uint32, tag = "2")]
+    pub end_height: u32,
+}
+/// GetBlockHeadersByHeightResponse contains headers and metadata between heights.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersByHeightResponse {
+    /// List of serialized block headers
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub block_headers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// List of serialized metadata
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub metas: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// GetBlocksByHeightRequest requests full blocks between two heights.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetBlocksByHeightRequest {
+    /// Starting height
+    #[prost(uint32, tag = "1")]
+    pub start_height: u32,
+    /// Ending height
+    #[prost(uint32, tag = "2")]
+    pub end_height: u32,
+}
+/// GetBlocksByHeightResponse contains full blocks between heights.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlocksByHeightResponse {
+    /// List of serialized full blocks
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub blocks: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// FindBlocksContainingSubtreeRequest specifies a subtree hash to search for.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindBlocksContainingSubtreeRequest {
+    /// Subtree hash to find
+    #[prost(bytes = "vec", tag = "1")]
+    pub subtree_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Maximum number of blocks to return (0 = no limit)
+    #[prost(uint32, tag = "2")]
+    pub max_blocks: u32,
+}
+/// FindBlocksContainingSubtreeResponse contains blocks that contain the subtree.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindBlocksContainingSubtreeResponse {
+    /// List of serialized full blocks containing the subtree
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub blocks: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// GetBlockHeaderIDsResponse contains block header identifiers.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeaderIDsResponse {
+    /// List of block header IDs
+    #[prost(uint32, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<u32>,
+}
+/// GetMedianTimeResponse contains block timestamp information.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMedianTimeResponse {
+    /// Timestamps of the last 11 (+1) blocks
+    #[prost(uint32, repeated, tag = "1")]
+    pub block_header_time: ::prost::alloc::vec::Vec<u32>,
+}
+/// GetBlockHeaderRequest requests a specific block header.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeaderRequest {
+    /// Hash of the block
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// CheckBlockIsCurrentChainRequest checks if blocks are in the main chain.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckBlockIsCurrentChainRequest {
+    /// List of block IDs to check
+    #[prost(uint32, repeated, tag = "1")]
+    pub block_i_ds: ::prost::alloc::vec::Vec<u32>,
+}
+/// InvalidateBlockRequest requests to invalidate a block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InvalidateBlockRequest {
+    /// Hash of the block to invalidate
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// InvalidateBlockResponse contains the result of block invalidation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InvalidateBlockResponse {
+    /// List of invalidated block hashes
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub invalidated_blocks: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// RevalidateBlockRequest requests to revalidate a block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevalidateBlockRequest {
+    /// Hash of the block to revalidate
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlockHeaderResponse contains detailed block header information.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeaderResponse {
+    /// Serialized block header
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_header: ::prost::alloc::vec::Vec<u8>,
+    /// Block identifier
+    #[prost(uint32, tag = "2")]
+    pub id: u32,
+    /// Block height
+    #[prost(uint32, tag = "3")]
+    pub height: u32,
+    /// Transaction count
+    #[prost(uint64, tag = "4")]
+    pub tx_count: u64,
+    /// Block size
+    #[prost(uint64, tag = "5")]
+    pub size_in_bytes: u64,
+    /// Miner identifier
+    #[prost(string, tag = "6")]
+    pub miner: ::prost::alloc::string::String,
+    /// Peer identifier
+    #[prost(string, tag = "7")]
+    pub peer_id: ::prost::alloc::string::String,
+    /// Block timestamp
+    #[prost(uint32, tag = "8")]
+    pub block_time: u32,
+    /// Processing timestamp
+    #[prost(uint32, tag = "9")]
+    pub timestamp: u32,
+    /// Accumulated chain work
+    #[prost(bytes = "vec", tag = "10")]
+    pub chain_work: ::prost::alloc::vec::Vec<u8>,
+    /// Mined status
+    #[prost(bool, tag = "11")]
+    pub mined_set: bool,
+    /// Subtrees status
+    #[prost(bool, tag = "12")]
+    pub subtrees_set: bool,
+    /// Validity status
+    #[prost(bool, tag = "13")]
+    pub invalid: bool,
+    /// Timestamp when block was processed
+    #[prost(message, optional, tag = "14")]
+    pub processed_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// CheckBlockIsCurrentChainResponse indicates if blocks are in the main chain.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CheckBlockIsCurrentChainResponse {
+    /// True if blocks are in main chain
+    #[prost(bool, tag = "1")]
+    pub is_part_of_current_chain: bool,
+}
+/// SubscribeRequest initiates a subscription to blockchain events.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeRequest {
+    /// Source identifier for the subscription
+    #[prost(string, tag = "1")]
+    pub source: ::prost::alloc::string::String,
+}
+/// Notification represents a blockchain event notification.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Notification {
+    /// Type of notification
+    #[prost(enumeration = "super::model::NotificationType", tag = "1")]
+    pub r#type: i32,
+    /// Related block hash
+    #[prost(bytes = "vec", tag = "2")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// Base URL for additional data
+    #[prost(string, tag = "3")]
+    pub base_url: ::prost::alloc::string::String,
+    /// Additional metadata
+    #[prost(message, optional, tag = "4")]
+    pub metadata: ::core::option::Option<NotificationMetadata>,
+}
+/// NotificationMetadata contains additional notification information.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotificationMetadata {
+    /// Key-value pairs of metadata
+    #[prost(map = "string, string", tag = "1")]
+    pub metadata: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+}
+/// GetStateRequest requests state data by key.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStateRequest {
+    /// State key to retrieve
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+}
+/// StateResponse contains state data.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateResponse {
+    /// State data
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// SetStateRequest sets state data for a key.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetStateRequest {
+    /// State key
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// State data to store
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlockIsMinedRequest checks if a block is marked as mined.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockIsMinedRequest {
+    /// Hash of the block
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlockIsMinedResponse indicates if a block is marked as mined.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetBlockIsMinedResponse {
+    /// True if the block is marked as mined
+    #[prost(bool, tag = "1")]
+    pub is_mined: bool,
+}
+/// GetLastNBlocksRequest requests the most recent blocks.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetLastNBlocksRequest {
+    /// Number of blocks to retrieve
+    #[prost(int64, tag = "1")]
+    pub number_of_blocks: i64,
+    /// Whether to include orphaned blocks
+    #[prost(bool, tag = "2")]
+    pub include_orphans: bool,
+    /// Starting height
+    #[prost(uint32, tag = "3")]
+    pub from_height: u32,
+}
+/// GetLastNBlocksResponse contains recent block information.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLastNBlocksResponse {
+    /// List of block information
+    #[prost(message, repeated, tag = "1")]
+    pub blocks: ::prost::alloc::vec::Vec<super::model::BlockInfo>,
+}
+/// GetLastNInvalidBlocksRequest requests the most recent invalid blocks.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetLastNInvalidBlocksRequest {
+    /// Number of blocks to retrieve
+    #[prost(int64, tag = "1")]
+    pub n: i64,
+}
+/// GetLastNInvalidBlocksResponse contains recent invalid block information.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLastNInvalidBlocksResponse {
+    /// List of block information
+    #[prost(message, repeated, tag = "1")]
+    pub blocks: ::prost::alloc::vec::Vec<super::model::BlockInfo>,
+}
+/// GetSuitableBlockRequest requests a suitable block for mining.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSuitableBlockRequest {
+    /// Reference block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetSuitableBlockResponse contains a suitable block for mining.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSuitableBlockResponse {
+    /// Suitable block information
+    #[prost(message, optional, tag = "1")]
+    pub block: ::core::option::Option<super::model::SuitableBlock>,
+}
+/// GetHashOfAncestorBlockRequest requests an ancestor block hash.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetHashOfAncestorBlockRequest {
+    /// Starting block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// Number of generations to go back
+    #[prost(uint32, tag = "2")]
+    pub depth: u32,
+}
+/// GetLatestBlockHeaderFromBlockLocatorRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLatestBlockHeaderFromBlockLocatorRequest {
+    /// Best block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub best_block_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Block locator hashes
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub block_locator_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// GetBlockHeadersFromOldestRequest retrieves block headers starting from the oldest block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockHeadersFromOldestRequest {
+    /// Chain tip hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub chain_tip_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Target block hash
+    #[prost(bytes = "vec", tag = "2")]
+    pub target_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Maximum number of hashes to return
+    #[prost(uint64, tag = "3")]
+    pub number_of_headers: u64,
+}
+/// GetHashOfAncestorBlockResponse contains an ancestor block hash.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetHashOfAncestorBlockResponse {
+    /// Ancestor block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetNextWorkRequiredRequest requests difficulty calculation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNextWorkRequiredRequest {
+    /// Reference block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub previous_block_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Current block time is only used for emaergency difficulty adjustment on testnet-type networks
+    #[prost(int64, tag = "2")]
+    pub current_block_time: i64,
+}
+/// GetNextWorkRequiredResponse contains difficulty target.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNextWorkRequiredResponse {
+    /// Difficulty bits
+    #[prost(bytes = "vec", tag = "1")]
+    pub bits: ::prost::alloc::vec::Vec<u8>,
+}
+/// SetBlockMinedSetRequest marks a block as mined.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetBlockMinedSetRequest {
+    /// Hash of the mined block
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlocksMinedNotSetResponse contains blocks not marked as mined.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlocksMinedNotSetResponse {
+    /// List of serialized blocks
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub block_bytes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// SetBlockSubtreesSetRequest marks block subtrees as set.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetBlockSubtreesSetRequest {
+    /// Block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetBlocksSubtreesNotSetResponse contains blocks with unset subtrees.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlocksSubtreesNotSetResponse {
+    /// List of serialized blocks
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub block_bytes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// SetBlockProcessedAtRequest defines parameters for setting or clearing a block's processed_at timestamp.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetBlockProcessedAtRequest {
+    /// Block hash to set or clear the processed_at timestamp for
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+    /// If true, the processed_at timestamp will be cleared (set to NULL)
+    /// If false, the processed_at timestamp will be set to the current time
+    #[prost(bool, tag = "2")]
+    pub clear: bool,
+}
+/// GetFSMStateResponse contains current FSM state.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetFsmStateResponse {
+    /// Current FSM state
+    #[prost(enumeration = "FsmStateType", tag = "1")]
+    pub state: i32,
+}
+/// WaitFSMToTransitionRequest specifies target FSM state.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct WaitFsmToTransitionRequest {
+    /// Target FSM state
+    #[prost(enumeration = "FsmStateType", tag = "1")]
+    pub state: i32,
+}
+/// SendFSMEventRequest triggers an FSM event.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SendFsmEventRequest {
+    /// FSM event to trigger
+    #[prost(enumeration = "FsmEventType", tag = "1")]
+    pub event: i32,
+}
+/// GetBlockLocatorRequest requests a block locator.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockLocatorRequest {
+    /// Reference block hash
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// Reference block height
+    #[prost(uint32, tag = "2")]
+    pub height: u32,
+}
+/// GetBlockLocatorResponse contains block locator data.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockLocatorResponse {
+    /// Block locator hashes
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub locator: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// LocateBlockHeadersRequest requests headers using a locator.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LocateBlockHeadersRequest {
+    /// Block locator
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub locator: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// Stop hash
+    #[prost(bytes = "vec", tag = "2")]
+    pub hash_stop: ::prost::alloc::vec::Vec<u8>,
+    /// Maximum number of hashes
+    #[prost(uint32, tag = "3")]
+    pub max_hashes: u32,
+}
+/// LocateBlockHeadersResponse contains located block headers.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LocateBlockHeadersResponse {
+    /// List of block headers
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub block_headers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// GetBestHeightAndTimeResponse contains chain tip information.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetBestHeightAndTimeResponse {
+    /// Best block height
+    #[prost(uint32, tag = "1")]
+    pub height: u32,
+    /// Median time
+    #[prost(uint32, tag = "2")]
+    pub time: u32,
+}
+/// GetChainTipsResponse contains information about all known tips in the block tree.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetChainTipsResponse {
+    /// List of chain tips
+    #[prost(message, repeated, tag = "1")]
+    pub tips: ::prost::alloc::vec::Vec<super::model::ChainTip>,
+}
+/// ReportPeerFailureRequest reports a peer download failure to the blockchain service.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReportPeerFailureRequest {
+    /// Hash of the block/subtree being processed
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// Identifier of the failing peer
+    #[prost(string, tag = "2")]
+    pub peer_id: ::prost::alloc::string::String,
+    /// Type of failure (e.g., "catchup", "subtree", "block")
+    #[prost(string, tag = "3")]
+    pub failure_type: ::prost::alloc::string::String,
+    /// Description of the failure
+    #[prost(string, tag = "4")]
+    pub reason: ::prost::alloc::string::String,
+}
+/// swagger:enum FSMEventType
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FsmEventType {
+    Stop = 0,
+    Run = 1,
+    Catchupblocks = 2,
+    Legacysync = 3,
+}
+impl FsmEventType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Stop => "STOP",
+            Self::Run => "RUN",
+            Self::Catchupblocks => "CATCHUPBLOCKS",
+            Self::Legacysync => "LEGACYSYNC",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STOP" => Some(Self::Stop),
+            "RUN" => Some(Self::Run),
+            "CATCHUPBLOCKS" => Some(Self::Catchupblocks),
+            "LEGACYSYNC" => Some(Self::Legacysync),
+            _ => None,
+        }
+    }
+}
+/// FSMStateType defines possible states of the blockchain FSM.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FsmStateType {
+    /// Service is idle
+    Idle = 0,
+    /// Service is running normally
+    Running = 1,
+    /// Service is catching up blocks
+    Catchingblocks = 2,
+    /// Service is in legacy sync mode
+    Legacysyncing = 3,
+}
+impl FsmStateType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Idle => "IDLE",
+            Self::Running => "RUNNING",
+            Self::Catchingblocks => "CATCHINGBLOCKS",
+            Self::Legacysyncing => "LEGACYSYNCING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "IDLE" => Some(Self::Idle),
+            "RUNNING" => Some(Self::Running),
+            "CATCHINGBLOCKS" => Some(Self::Catchingblocks),
+            "LEGACYSYNCING" => Some(Self::Legacysyncing),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod blockchain_api_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// BlockchainAPI service provides comprehensive blockchain management functionality.
+    #[derive(Debug, Clone)]
+    pub struct BlockchainApiClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl BlockchainApiClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> BlockchainApiClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> BlockchainApiClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            BlockchainApiClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// HealthGRPC checks the health status of the blockchain service.
+        pub async fn health_grpc(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<tonic::Response<super::HealthResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/HealthGRPC",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "HealthGRPC"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// AddBlock adds a new block to the blockchain.
+        /// Called by BlockValidator to add validated blocks.
+        pub async fn add_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddBlockRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/AddBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "AddBlock"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlock retrieves a block by its hash.
+        pub async fn get_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlock"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlocks retrieves multiple blocks starting from a specific hash.
+        pub async fn get_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlocks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlocks"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockByHeight retrieves a block at a specific height.
+        pub async fn get_block_by_height(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockByHeight",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockByHeight"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockByID retrieves a block by its id.
+        pub async fn get_block_by_id(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockByIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockByID",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockByID"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetNextBlockID retrieves the next available block ID.
+        pub async fn get_next_block_id(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNextBlockIdResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetNextBlockID",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetNextBlockID"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockStats retrieves statistical information about the blockchain.
+        pub async fn get_block_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::model::BlockStats>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockStats"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockGraphData retrieves data points for blockchain visualization.
+        pub async fn get_block_graph_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockGraphDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::model::BlockDataPoints>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockGraphData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockGraphData"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetLastNBlocks retrieves the most recent N blocks from the blockchain.
+        pub async fn get_last_n_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLastNBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLastNBlocksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetLastNBlocks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetLastNBlocks"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetLastNInvalidBlocks retrieves the most recent N blocks that have been marked as invalid.
+        pub async fn get_last_n_invalid_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLastNInvalidBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLastNInvalidBlocksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetLastNInvalidBlocks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetLastNInvalidBlocks",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetSuitableBlock finds a suitable block for mining purposes.
+        pub async fn get_suitable_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSuitableBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetSuitableBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetSuitableBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetSuitableBlock"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetHashOfAncestorBlock retrieves the hash of an ancestor block at a specified depth.
+        pub async fn get_hash_of_ancestor_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetHashOfAncestorBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetHashOfAncestorBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetHashOfAncestorBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetHashOfAncestorBlock",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetLatestBlockHeaderFromBlockLocator retrieves the latest block header using a block locator.
+        pub async fn get_latest_block_header_from_block_locator(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::GetLatestBlockHeaderFromBlockLocatorRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetLatestBlockHeaderFromBlockLocator",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetLatestBlockHeaderFromBlockLocator",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockHeadersFromOldest retrieves block headers starting from the oldest block.
+        pub async fn get_block_headers_from_oldest(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockHeadersFromOldestRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersFromOldest",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetBlockHeadersFromOldest",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetNextWorkRequired calculates the required proof of work for the next block.
+        pub async fn get_next_work_required(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNextWorkRequiredRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNextWorkRequiredResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetNextWorkRequired",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetNextWorkRequired",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockExists checks if a block exists in the blockchain.
+        pub async fn get_block_exists(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockExistsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockExists",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockExists"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockHeaders retrieves headers for multiple blocks.
+        pub async fn get_block_headers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockHeadersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockHeaders",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockHeaders"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockHeadersToCommonAncestor retrieves headers from a block to its common ancestor.
+        pub async fn get_block_headers_to_common_ancestor(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::GetBlockHeadersToCommonAncestorRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersToCommonAncestor",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetBlockHeadersToCommonAncestor",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockHeadersFromCommonAncestor retrieves headers from a common ancestor to a target block.
+        pub async fn get_block_headers_from_common_ancestor(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::GetBlockHeadersFromCommonAncestorRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersFromCommonAncestor",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetBlockHeadersFromCommonAncestor",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockHeadersFromTill retrieves block headers between two specified blocks.
+        pub async fn get_block_headers_from_till(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockHeadersFromTillRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersFromTill",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetBlockHeadersFromTill",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockHeadersFromHeight retrieves block headers starting from a specific height.
+        pub async fn get_block_headers_from_height(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockHeadersFromHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersFromHeightResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersFromHeight",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetBlockHeadersFromHeight",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockHeadersByHeight retrieves block headers between two specified heights.
+        pub async fn get_block_headers_by_height(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockHeadersByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersByHeightResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersByHeight",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetBlockHeadersByHeight",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlocksByHeight retrieves full blocks between two specified heights.
+        pub async fn get_blocks_by_height(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlocksByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksByHeightResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlocksByHeight",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlocksByHeight"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// FindBlocksContainingSubtree finds all blocks that contain the specified subtree hash.
+        pub async fn find_blocks_containing_subtree(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindBlocksContainingSubtreeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FindBlocksContainingSubtreeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/FindBlocksContainingSubtree",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "FindBlocksContainingSubtree",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockHeaderIDs retrieves block header IDs for a range of blocks.
+        pub async fn get_block_header_i_ds(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockHeadersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderIDsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockHeaderIDs",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockHeaderIDs"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBestBlockHeader retrieves the header of the current best block.
+        pub async fn get_best_block_header(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBestBlockHeader",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBestBlockHeader"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// CheckBlockIsInCurrentChain verifies if specified blocks are in the main chain.
+        pub async fn check_block_is_in_current_chain(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckBlockIsCurrentChainRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CheckBlockIsCurrentChainResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/CheckBlockIsInCurrentChain",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "CheckBlockIsInCurrentChain",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetChainTips retrieves information about all known tips in the block tree.
+        pub async fn get_chain_tips(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetChainTipsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetChainTips",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "GetChainTips"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockHeader retrieves the header of a specific block.
+        pub async fn get_block_header(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockHeaderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockHeader",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockHeader"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// InvalidateBlock marks a block as invalid in the blockchain.
+        pub async fn invalidate_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InvalidateBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::InvalidateBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/InvalidateBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "InvalidateBlock"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// RevalidateBlock restores a previously invalidated block.
+        pub async fn revalidate_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RevalidateBlockRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/RevalidateBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "RevalidateBlock"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Subscribe creates a subscription for blockchain notifications.
+        pub async fn subscribe(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::Notification>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/Subscribe",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "Subscribe"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// SendNotification broadcasts a notification to subscribers.
+        pub async fn send_notification(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Notification>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/SendNotification",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "SendNotification"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetState retrieves state data by key.
+        pub async fn get_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStateRequest>,
+        ) -> std::result::Result<tonic::Response<super::StateResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetState",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "GetState"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// SetState stores state data with a key.
+        pub async fn set_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetStateRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/SetState",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "SetState"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockIsMined checks if a block is marked as mined.
+        pub async fn get_block_is_mined(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockIsMinedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockIsMinedResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockIsMined",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockIsMined"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// SetBlockMinedSet marks a block as mined.
+        pub async fn set_block_mined_set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetBlockMinedSetRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/SetBlockMinedSet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "SetBlockMinedSet"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlocksMinedNotSet retrieves blocks not marked as mined.
+        pub async fn get_blocks_mined_not_set(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksMinedNotSetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlocksMinedNotSet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetBlocksMinedNotSet",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// SetBlockSubtreesSet marks a block's subtrees as set.
+        pub async fn set_block_subtrees_set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetBlockSubtreesSetRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/SetBlockSubtreesSet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "SetBlockSubtreesSet",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlocksSubtreesNotSet retrieves blocks with unset subtrees.
+        pub async fn get_blocks_subtrees_not_set(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksSubtreesNotSetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlocksSubtreesNotSet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetBlocksSubtreesNotSet",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// SetBlockProcessedAt sets or clears the processed_at timestamp for a block.
+        pub async fn set_block_processed_at(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetBlockProcessedAtRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/SetBlockProcessedAt",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "SetBlockProcessedAt",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// SendFSMEvent sends an event to the blockchain FSM.
+        pub async fn send_fsm_event(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SendFsmEventRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetFsmStateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/SendFSMEvent",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "SendFSMEvent"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetFSMCurrentState retrieves the current state of the FSM.
+        pub async fn get_fsm_current_state(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetFsmStateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetFSMCurrentState",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetFSMCurrentState"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// WaitFSMToTransitionToGivenState waits for FSM to reach a specific state.
+        pub async fn wait_fsm_to_transition_to_given_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WaitFsmToTransitionRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/WaitFSMToTransitionToGivenState",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "WaitFSMToTransitionToGivenState",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// WaitUntilFSMTransitionFromIdleState waits for FSM to transition from IDLE state.
+        pub async fn wait_until_fsm_transition_from_idle_state(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/WaitUntilFSMTransitionFromIdleState",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "WaitUntilFSMTransitionFromIdleState",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Run transitions the blockchain service to running state.
+        pub async fn run(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/Run",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "Run"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// CatchUpBlocks initiates block catch-up process.
+        pub async fn catch_up_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/CatchUpBlocks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "CatchUpBlocks"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// LegacySync initiates legacy synchronization process.
+        pub async fn legacy_sync(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/LegacySync",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "LegacySync"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Idle marks the service as idle.
+        pub async fn idle(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/Idle",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("blockchain_api.BlockchainAPI", "Idle"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// ReportPeerFailure notifies about peer download failures (catchup, subtree, block, etc).
+        pub async fn report_peer_failure(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReportPeerFailureRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/ReportPeerFailure",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "ReportPeerFailure"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBlockLocator retrieves a block locator for chain synchronization.
+        pub async fn get_block_locator(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockLocatorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockLocatorResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBlockLocator",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "GetBlockLocator"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// LocateBlockHeaders finds block headers using a locator.
+        pub async fn locate_block_headers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LocateBlockHeadersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LocateBlockHeadersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/LocateBlockHeaders",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("blockchain_api.BlockchainAPI", "LocateBlockHeaders"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetBestHeightAndTime retrieves the current best height and median time.
+        pub async fn get_best_height_and_time(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBestHeightAndTimeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/blockchain_api.BlockchainAPI/GetBestHeightAndTime",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "blockchain_api.BlockchainAPI",
+                        "GetBestHeightAndTime",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod blockchain_api_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with BlockchainApiServer.
+    #[async_trait]
+    pub trait BlockchainApi: std::marker::Send + std::marker::Sync + 'static {
+        /// HealthGRPC checks the health status of the blockchain service.
+        async fn health_grpc(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<tonic::Response<super::HealthResponse>, tonic::Status>;
+        /// AddBlock adds a new block to the blockchain.
+        /// Called by BlockValidator to add validated blocks.
+        async fn add_block(
+            &self,
+            request: tonic::Request<super::AddBlockRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// GetBlock retrieves a block by its hash.
+        async fn get_block(
+            &self,
+            request: tonic::Request<super::GetBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockResponse>,
+            tonic::Status,
+        >;
+        /// GetBlocks retrieves multiple blocks starting from a specific hash.
+        async fn get_blocks(
+            &self,
+            request: tonic::Request<super::GetBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockByHeight retrieves a block at a specific height.
+        async fn get_block_by_height(
+            &self,
+            request: tonic::Request<super::GetBlockByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockByID retrieves a block by its id.
+        async fn get_block_by_id(
+            &self,
+            request: tonic::Request<super::GetBlockByIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockResponse>,
+            tonic::Status,
+        >;
+        /// GetNextBlockID retrieves the next available block ID.
+        async fn get_next_block_id(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNextBlockIdResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockStats retrieves statistical information about the blockchain.
+        async fn get_block_stats(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::model::BlockStats>,
+            tonic::Status,
+        >;
+        /// GetBlockGraphData retrieves data points for blockchain visualization.
+        async fn get_block_graph_data(
+            &self,
+            request: tonic::Request<super::GetBlockGraphDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::model::BlockDataPoints>,
+            tonic::Status,
+        >;
+        /// GetLastNBlocks retrieves the most recent N blocks from the blockchain.
+        async fn get_last_n_blocks(
+            &self,
+            request: tonic::Request<super::GetLastNBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLastNBlocksResponse>,
+            tonic::Status,
+        >;
+        /// GetLastNInvalidBlocks retrieves the most recent N blocks that have been marked as invalid.
+        async fn get_last_n_invalid_blocks(
+            &self,
+            request: tonic::Request<super::GetLastNInvalidBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLastNInvalidBlocksResponse>,
+            tonic::Status,
+        >;
+        /// GetSuitableBlock finds a suitable block for mining purposes.
+        async fn get_suitable_block(
+            &self,
+            request: tonic::Request<super::GetSuitableBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetSuitableBlockResponse>,
+            tonic::Status,
+        >;
+        /// GetHashOfAncestorBlock retrieves the hash of an ancestor block at a specified depth.
+        async fn get_hash_of_ancestor_block(
+            &self,
+            request: tonic::Request<super::GetHashOfAncestorBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetHashOfAncestorBlockResponse>,
+            tonic::Status,
+        >;
+        /// GetLatestBlockHeaderFromBlockLocator retrieves the latest block header using a block locator.
+        async fn get_latest_block_header_from_block_locator(
+            &self,
+            request: tonic::Request<super::GetLatestBlockHeaderFromBlockLocatorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockHeadersFromOldest retrieves block headers starting from the oldest block.
+        async fn get_block_headers_from_oldest(
+            &self,
+            request: tonic::Request<super::GetBlockHeadersFromOldestRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        >;
+        /// GetNextWorkRequired calculates the required proof of work for the next block.
+        async fn get_next_work_required(
+            &self,
+            request: tonic::Request<super::GetNextWorkRequiredRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNextWorkRequiredResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockExists checks if a block exists in the blockchain.
+        async fn get_block_exists(
+            &self,
+            request: tonic::Request<super::GetBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockExistsResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockHeaders retrieves headers for multiple blocks.
+        async fn get_block_headers(
+            &self,
+            request: tonic::Request<super::GetBlockHeadersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockHeadersToCommonAncestor retrieves headers from a block to its common ancestor.
+        async fn get_block_headers_to_common_ancestor(
+            &self,
+            request: tonic::Request<super::GetBlockHeadersToCommonAncestorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockHeadersFromCommonAncestor retrieves headers from a common ancestor to a target block.
+        async fn get_block_headers_from_common_ancestor(
+            &self,
+            request: tonic::Request<super::GetBlockHeadersFromCommonAncestorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockHeadersFromTill retrieves block headers between two specified blocks.
+        async fn get_block_headers_from_till(
+            &self,
+            request: tonic::Request<super::GetBlockHeadersFromTillRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockHeadersFromHeight retrieves block headers starting from a specific height.
+        async fn get_block_headers_from_height(
+            &self,
+            request: tonic::Request<super::GetBlockHeadersFromHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersFromHeightResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockHeadersByHeight retrieves block headers between two specified heights.
+        async fn get_block_headers_by_height(
+            &self,
+            request: tonic::Request<super::GetBlockHeadersByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeadersByHeightResponse>,
+            tonic::Status,
+        >;
+        /// GetBlocksByHeight retrieves full blocks between two specified heights.
+        async fn get_blocks_by_height(
+            &self,
+            request: tonic::Request<super::GetBlocksByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksByHeightResponse>,
+            tonic::Status,
+        >;
+        /// FindBlocksContainingSubtree finds all blocks that contain the specified subtree hash.
+        async fn find_blocks_containing_subtree(
+            &self,
+            request: tonic::Request<super::FindBlocksContainingSubtreeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FindBlocksContainingSubtreeResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockHeaderIDs retrieves block header IDs for a range of blocks.
+        async fn get_block_header_i_ds(
+            &self,
+            request: tonic::Request<super::GetBlockHeadersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderIDsResponse>,
+            tonic::Status,
+        >;
+        /// GetBestBlockHeader retrieves the header of the current best block.
+        async fn get_best_block_header(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderResponse>,
+            tonic::Status,
+        >;
+        /// CheckBlockIsInCurrentChain verifies if specified blocks are in the main chain.
+        async fn check_block_is_in_current_chain(
+            &self,
+            request: tonic::Request<super::CheckBlockIsCurrentChainRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CheckBlockIsCurrentChainResponse>,
+            tonic::Status,
+        >;
+        /// GetChainTips retrieves information about all known tips in the block tree.
+        async fn get_chain_tips(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetChainTipsResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockHeader retrieves the header of a specific block.
+        async fn get_block_header(
+            &self,
+            request: tonic::Request<super::GetBlockHeaderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockHeaderResponse>,
+            tonic::Status,
+        >;
+        /// InvalidateBlock marks a block as invalid in the blockchain.
+        async fn invalidate_block(
+            &self,
+            request: tonic::Request<super::InvalidateBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::InvalidateBlockResponse>,
+            tonic::Status,
+        >;
+        /// RevalidateBlock restores a previously invalidated block.
+        async fn revalidate_block(
+            &self,
+            request: tonic::Request<super::RevalidateBlockRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// Server streaming response type for the Subscribe method.
+        type SubscribeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::Notification, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Subscribe creates a subscription for blockchain notifications.
+        async fn subscribe(
+            &self,
+            request: tonic::Request<super::SubscribeRequest>,
+        ) -> std::result::Result<tonic::Response<Self::SubscribeStream>, tonic::Status>;
+        /// SendNotification broadcasts a notification to subscribers.
+        async fn send_notification(
+            &self,
+            request: tonic::Request<super::Notification>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// GetState retrieves state data by key.
+        async fn get_state(
+            &self,
+            request: tonic::Request<super::GetStateRequest>,
+        ) -> std::result::Result<tonic::Response<super::StateResponse>, tonic::Status>;
+        /// SetState stores state data with a key.
+        async fn set_state(
+            &self,
+            request: tonic::Request<super::SetStateRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// GetBlockIsMined checks if a block is marked as mined.
+        async fn get_block_is_mined(
+            &self,
+            request: tonic::Request<super::GetBlockIsMinedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockIsMinedResponse>,
+            tonic::Status,
+        >;
+        /// SetBlockMinedSet marks a block as mined.
+        async fn set_block_mined_set(
+            &self,
+            request: tonic::Request<super::SetBlockMinedSetRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// GetBlocksMinedNotSet retrieves blocks not marked as mined.
+        async fn get_blocks_mined_not_set(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksMinedNotSetResponse>,
+            tonic::Status,
+        >;
+        /// SetBlockSubtreesSet marks a block's subtrees as set.
+        async fn set_block_subtrees_set(
+            &self,
+            request: tonic::Request<super::SetBlockSubtreesSetRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// GetBlocksSubtreesNotSet retrieves blocks with unset subtrees.
+        async fn get_blocks_subtrees_not_set(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksSubtreesNotSetResponse>,
+            tonic::Status,
+        >;
+        /// SetBlockProcessedAt sets or clears the processed_at timestamp for a block.
+        async fn set_block_processed_at(
+            &self,
+            request: tonic::Request<super::SetBlockProcessedAtRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// SendFSMEvent sends an event to the blockchain FSM.
+        async fn send_fsm_event(
+            &self,
+            request: tonic::Request<super::SendFsmEventRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetFsmStateResponse>,
+            tonic::Status,
+        >;
+        /// GetFSMCurrentState retrieves the current state of the FSM.
+        async fn get_fsm_current_state(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetFsmStateResponse>,
+            tonic::Status,
+        >;
+        /// WaitFSMToTransitionToGivenState waits for FSM to reach a specific state.
+        async fn wait_fsm_to_transition_to_given_state(
+            &self,
+            request: tonic::Request<super::WaitFsmToTransitionRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// WaitUntilFSMTransitionFromIdleState waits for FSM to transition from IDLE state.
+        async fn wait_until_fsm_transition_from_idle_state(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// Run transitions the blockchain service to running state.
+        async fn run(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// CatchUpBlocks initiates block catch-up process.
+        async fn catch_up_blocks(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// LegacySync initiates legacy synchronization process.
+        async fn legacy_sync(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// Idle marks the service as idle.
+        async fn idle(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// ReportPeerFailure notifies about peer download failures (catchup, subtree, block, etc).
+        async fn report_peer_failure(
+            &self,
+            request: tonic::Request<super::ReportPeerFailureRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        /// GetBlockLocator retrieves a block locator for chain synchronization.
+        async fn get_block_locator(
+            &self,
+            request: tonic::Request<super::GetBlockLocatorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockLocatorResponse>,
+            tonic::Status,
+        >;
+        /// LocateBlockHeaders finds block headers using a locator.
+        async fn locate_block_headers(
+            &self,
+            request: tonic::Request<super::LocateBlockHeadersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LocateBlockHeadersResponse>,
+            tonic::Status,
+        >;
+        /// GetBestHeightAndTime retrieves the current best height and median time.
+        async fn get_best_height_and_time(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBestHeightAndTimeResponse>,
+            tonic::Status,
+        >;
+    }
+    /// BlockchainAPI service provides comprehensive blockchain management functionality.
+    #[derive(Debug)]
+    pub struct BlockchainApiServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> BlockchainApiServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for BlockchainApiServer<T>
+    where
+        T: BlockchainApi,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/blockchain_api.BlockchainAPI/HealthGRPC" => {
+                    #[allow(non_camel_case_types)]
+                    struct HealthGRPCSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for HealthGRPCSvc<T> {
+                        type Response = super::HealthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::health_grpc(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HealthGRPCSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/AddBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddBlockSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::AddBlockRequest>
+                    for AddBlockSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::add_block(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockRequest>
+                    for GetBlockSvc<T> {
+                        type Response = super::GetBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlocksSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlocksRequest>
+                    for GetBlocksSvc<T> {
+                        type Response = super::GetBlocksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlocksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_blocks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockByHeight" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockByHeightSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockByHeightRequest>
+                    for GetBlockByHeightSvc<T> {
+                        type Response = super::GetBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockByHeightRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_by_height(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockByHeightSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockByID" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockByIDSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockByIdRequest>
+                    for GetBlockByIDSvc<T> {
+                        type Response = super::GetBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockByIdRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_by_id(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockByIDSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetNextBlockID" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNextBlockIDSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for GetNextBlockIDSvc<T> {
+                        type Response = super::GetNextBlockIdResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_next_block_id(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetNextBlockIDSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockStatsSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for GetBlockStatsSvc<T> {
+                        type Response = super::super::model::BlockStats;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockGraphData" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockGraphDataSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockGraphDataRequest>
+                    for GetBlockGraphDataSvc<T> {
+                        type Response = super::super::model::BlockDataPoints;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockGraphDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_graph_data(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockGraphDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetLastNBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLastNBlocksSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetLastNBlocksRequest>
+                    for GetLastNBlocksSvc<T> {
+                        type Response = super::GetLastNBlocksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLastNBlocksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_last_n_blocks(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetLastNBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetLastNInvalidBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLastNInvalidBlocksSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetLastNInvalidBlocksRequest>
+                    for GetLastNInvalidBlocksSvc<T> {
+                        type Response = super::GetLastNInvalidBlocksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLastNInvalidBlocksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_last_n_invalid_blocks(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetLastNInvalidBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetSuitableBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSuitableBlockSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetSuitableBlockRequest>
+                    for GetSuitableBlockSvc<T> {
+                        type Response = super::GetSuitableBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetSuitableBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_suitable_block(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetSuitableBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetHashOfAncestorBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetHashOfAncestorBlockSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetHashOfAncestorBlockRequest>
+                    for GetHashOfAncestorBlockSvc<T> {
+                        type Response = super::GetHashOfAncestorBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetHashOfAncestorBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_hash_of_ancestor_block(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetHashOfAncestorBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetLatestBlockHeaderFromBlockLocator" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLatestBlockHeaderFromBlockLocatorSvc<T: BlockchainApi>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<
+                        super::GetLatestBlockHeaderFromBlockLocatorRequest,
+                    > for GetLatestBlockHeaderFromBlockLocatorSvc<T> {
+                        type Response = super::GetBlockHeaderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetLatestBlockHeaderFromBlockLocatorRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_latest_block_header_from_block_locator(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetLatestBlockHeaderFromBlockLocatorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersFromOldest" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeadersFromOldestSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<
+                        super::GetBlockHeadersFromOldestRequest,
+                    > for GetBlockHeadersFromOldestSvc<T> {
+                        type Response = super::GetBlockHeadersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetBlockHeadersFromOldestRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_headers_from_oldest(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeadersFromOldestSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetNextWorkRequired" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNextWorkRequiredSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetNextWorkRequiredRequest>
+                    for GetNextWorkRequiredSvc<T> {
+                        type Response = super::GetNextWorkRequiredResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetNextWorkRequiredRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_next_work_required(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetNextWorkRequiredSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockExists" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockExistsSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockRequest>
+                    for GetBlockExistsSvc<T> {
+                        type Response = super::GetBlockExistsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_exists(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockExistsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockHeaders" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeadersSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockHeadersRequest>
+                    for GetBlockHeadersSvc<T> {
+                        type Response = super::GetBlockHeadersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockHeadersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_headers(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeadersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersToCommonAncestor" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeadersToCommonAncestorSvc<T: BlockchainApi>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<
+                        super::GetBlockHeadersToCommonAncestorRequest,
+                    > for GetBlockHeadersToCommonAncestorSvc<T> {
+                        type Response = super::GetBlockHeadersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetBlockHeadersToCommonAncestorRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_headers_to_common_ancestor(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeadersToCommonAncestorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersFromCommonAncestor" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeadersFromCommonAncestorSvc<T: BlockchainApi>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<
+                        super::GetBlockHeadersFromCommonAncestorRequest,
+                    > for GetBlockHeadersFromCommonAncestorSvc<T> {
+                        type Response = super::GetBlockHeadersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetBlockHeadersFromCommonAncestorRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_headers_from_common_ancestor(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeadersFromCommonAncestorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersFromTill" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeadersFromTillSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockHeadersFromTillRequest>
+                    for GetBlockHeadersFromTillSvc<T> {
+                        type Response = super::GetBlockHeadersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetBlockHeadersFromTillRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_headers_from_till(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeadersFromTillSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersFromHeight" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeadersFromHeightSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<
+                        super::GetBlockHeadersFromHeightRequest,
+                    > for GetBlockHeadersFromHeightSvc<T> {
+                        type Response = super::GetBlockHeadersFromHeightResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetBlockHeadersFromHeightRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_headers_from_height(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeadersFromHeightSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockHeadersByHeight" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeadersByHeightSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockHeadersByHeightRequest>
+                    for GetBlockHeadersByHeightSvc<T> {
+                        type Response = super::GetBlockHeadersByHeightResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetBlockHeadersByHeightRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_headers_by_height(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeadersByHeightSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlocksByHeight" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlocksByHeightSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlocksByHeightRequest>
+                    for GetBlocksByHeightSvc<T> {
+                        type Response = super::GetBlocksByHeightResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlocksByHeightRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_blocks_by_height(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlocksByHeightSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/FindBlocksContainingSubtree" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindBlocksContainingSubtreeSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<
+                        super::FindBlocksContainingSubtreeRequest,
+                    > for FindBlocksContainingSubtreeSvc<T> {
+                        type Response = super::FindBlocksContainingSubtreeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::FindBlocksContainingSubtreeRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::find_blocks_containing_subtree(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindBlocksContainingSubtreeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockHeaderIDs" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeaderIDsSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockHeadersRequest>
+                    for GetBlockHeaderIDsSvc<T> {
+                        type Response = super::GetBlockHeaderIDsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockHeadersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_header_i_ds(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeaderIDsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBestBlockHeader" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBestBlockHeaderSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for GetBestBlockHeaderSvc<T> {
+                        type Response = super::GetBlockHeaderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_best_block_header(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBestBlockHeaderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/CheckBlockIsInCurrentChain" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckBlockIsInCurrentChainSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::CheckBlockIsCurrentChainRequest>
+                    for CheckBlockIsInCurrentChainSvc<T> {
+                        type Response = super::CheckBlockIsCurrentChainResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::CheckBlockIsCurrentChainRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::check_block_is_in_current_chain(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CheckBlockIsInCurrentChainSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetChainTips" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetChainTipsSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for GetChainTipsSvc<T> {
+                        type Response = super::GetChainTipsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_chain_tips(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetChainTipsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockHeader" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeaderSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockHeaderRequest>
+                    for GetBlockHeaderSvc<T> {
+                        type Response = super::GetBlockHeaderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockHeaderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_header(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockHeaderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/InvalidateBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct InvalidateBlockSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::InvalidateBlockRequest>
+                    for InvalidateBlockSvc<T> {
+                        type Response = super::InvalidateBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::InvalidateBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::invalidate_block(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = InvalidateBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/RevalidateBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevalidateBlockSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::RevalidateBlockRequest>
+                    for RevalidateBlockSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RevalidateBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::revalidate_block(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RevalidateBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/Subscribe" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::ServerStreamingService<super::SubscribeRequest>
+                    for SubscribeSvc<T> {
+                        type Response = super::Notification;
+                        type ResponseStream = T::SubscribeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::subscribe(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/SendNotification" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendNotificationSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::Notification>
+                    for SendNotificationSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Notification>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::send_notification(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SendNotificationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetState" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStateSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetStateRequest>
+                    for GetStateSvc<T> {
+                        type Response = super::StateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_state(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/SetState" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetStateSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::SetStateRequest>
+                    for SetStateSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetStateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::set_state(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockIsMined" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockIsMinedSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockIsMinedRequest>
+                    for GetBlockIsMinedSvc<T> {
+                        type Response = super::GetBlockIsMinedResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockIsMinedRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_is_mined(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockIsMinedSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/SetBlockMinedSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetBlockMinedSetSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::SetBlockMinedSetRequest>
+                    for SetBlockMinedSetSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetBlockMinedSetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::set_block_mined_set(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetBlockMinedSetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlocksMinedNotSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlocksMinedNotSetSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for GetBlocksMinedNotSetSvc<T> {
+                        type Response = super::GetBlocksMinedNotSetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_blocks_mined_not_set(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlocksMinedNotSetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/SetBlockSubtreesSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetBlockSubtreesSetSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::SetBlockSubtreesSetRequest>
+                    for SetBlockSubtreesSetSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetBlockSubtreesSetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::set_block_subtrees_set(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetBlockSubtreesSetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlocksSubtreesNotSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlocksSubtreesNotSetSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for GetBlocksSubtreesNotSetSvc<T> {
+                        type Response = super::GetBlocksSubtreesNotSetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_blocks_subtrees_not_set(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlocksSubtreesNotSetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/SetBlockProcessedAt" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetBlockProcessedAtSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::SetBlockProcessedAtRequest>
+                    for SetBlockProcessedAtSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetBlockProcessedAtRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::set_block_processed_at(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetBlockProcessedAtSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/SendFSMEvent" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendFSMEventSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::SendFsmEventRequest>
+                    for SendFSMEventSvc<T> {
+                        type Response = super::GetFsmStateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SendFsmEventRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::send_fsm_event(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SendFSMEventSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetFSMCurrentState" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetFSMCurrentStateSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for GetFSMCurrentStateSvc<T> {
+                        type Response = super::GetFsmStateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_fsm_current_state(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetFSMCurrentStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/WaitFSMToTransitionToGivenState" => {
+                    #[allow(non_camel_case_types)]
+                    struct WaitFSMToTransitionToGivenStateSvc<T: BlockchainApi>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::WaitFsmToTransitionRequest>
+                    for WaitFSMToTransitionToGivenStateSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WaitFsmToTransitionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::wait_fsm_to_transition_to_given_state(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WaitFSMToTransitionToGivenStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/WaitUntilFSMTransitionFromIdleState" => {
+                    #[allow(non_camel_case_types)]
+                    struct WaitUntilFSMTransitionFromIdleStateSvc<T: BlockchainApi>(
+                        pub Arc<T>,
+                    );
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for WaitUntilFSMTransitionFromIdleStateSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::wait_until_fsm_transition_from_idle_state(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WaitUntilFSMTransitionFromIdleStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/Run" => {
+                    #[allow(non_camel_case_types)]
+                    struct RunSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for RunSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::run(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RunSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/CatchUpBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct CatchUpBlocksSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for CatchUpBlocksSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::catch_up_blocks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CatchUpBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/LegacySync" => {
+                    #[allow(non_camel_case_types)]
+                    struct LegacySyncSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for LegacySyncSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::legacy_sync(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LegacySyncSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/Idle" => {
+                    #[allow(non_camel_case_types)]
+                    struct IdleSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for IdleSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::idle(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = IdleSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/ReportPeerFailure" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReportPeerFailureSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::ReportPeerFailureRequest>
+                    for ReportPeerFailureSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReportPeerFailureRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::report_peer_failure(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReportPeerFailureSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBlockLocator" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockLocatorSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::GetBlockLocatorRequest>
+                    for GetBlockLocatorSvc<T> {
+                        type Response = super::GetBlockLocatorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockLocatorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_block_locator(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockLocatorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/LocateBlockHeaders" => {
+                    #[allow(non_camel_case_types)]
+                    struct LocateBlockHeadersSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<
+                        T: BlockchainApi,
+                    > tonic::server::UnaryService<super::LocateBlockHeadersRequest>
+                    for LocateBlockHeadersSvc<T> {
+                        type Response = super::LocateBlockHeadersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LocateBlockHeadersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::locate_block_headers(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LocateBlockHeadersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blockchain_api.BlockchainAPI/GetBestHeightAndTime" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBestHeightAndTimeSvc<T: BlockchainApi>(pub Arc<T>);
+                    impl<T: BlockchainApi> tonic::server::UnaryService<()>
+                    for GetBestHeightAndTimeSvc<T> {
+                        type Response = super::GetBestHeightAndTimeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockchainApi>::get_best_height_and_time(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBestHeightAndTimeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for BlockchainApiServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "blockchain_api.BlockchainAPI";
+    impl<T> tonic::server::NamedService for BlockchainApiServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
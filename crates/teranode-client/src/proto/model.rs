@@ -0,0 +1,183 @@
+// This file is @generated by prost-build.
+/// swagger:model MiningCandidate
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MiningCandidate {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub previous_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub coinbase_value: u64,
+    #[prost(uint32, tag = "4")]
+    pub version: u32,
+    #[prost(bytes = "vec", tag = "5")]
+    pub n_bits: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "6")]
+    pub time: u32,
+    #[prost(uint32, tag = "7")]
+    pub height: u32,
+    #[prost(bytes = "vec", repeated, tag = "8")]
+    pub merkle_proof: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "9")]
+    pub subtree_count: u32,
+    #[prost(uint32, tag = "10")]
+    pub num_txs: u32,
+    #[prost(uint64, tag = "11")]
+    pub size_without_coinbase: u64,
+    #[prost(bytes = "vec", repeated, tag = "12")]
+    pub subtree_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// swagger:model MiningSolution
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MiningSolution {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub coinbase: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, optional, tag = "3")]
+    pub time: ::core::option::Option<u32>,
+    #[prost(uint32, tag = "4")]
+    pub nonce: u32,
+    #[prost(uint32, optional, tag = "5")]
+    pub version: ::core::option::Option<u32>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// swagger:model NotificationMetadata
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotificationMetadata {
+    /// define a map of string to string
+    #[prost(map = "string, string", tag = "1")]
+    pub metadata: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+}
+/// swagger:model BlockInfo
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockInfo {
+    #[prost(message, optional, tag = "1")]
+    pub seen_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint32, tag = "2")]
+    pub height: u32,
+    #[prost(bool, tag = "3")]
+    pub orphaned: bool,
+    #[prost(bytes = "vec", tag = "4")]
+    pub block_header: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "5")]
+    pub miner: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "6")]
+    pub coinbase_value: u64,
+    #[prost(uint64, tag = "7")]
+    pub transaction_count: u64,
+    #[prost(uint64, tag = "8")]
+    pub size: u64,
+}
+/// swagger:model SuitableBlock
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuitableBlock {
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub height: u32,
+    #[prost(bytes = "vec", tag = "3")]
+    pub n_bits: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "4")]
+    pub time: u32,
+    #[prost(bytes = "vec", tag = "5")]
+    pub chain_work: ::prost::alloc::vec::Vec<u8>,
+}
+/// swagger:model BlockStats
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockStats {
+    #[prost(uint64, tag = "1")]
+    pub block_count: u64,
+    #[prost(uint64, tag = "2")]
+    pub tx_count: u64,
+    #[prost(uint64, tag = "3")]
+    pub max_height: u64,
+    #[prost(double, tag = "4")]
+    pub avg_block_size: f64,
+    #[prost(double, tag = "5")]
+    pub avg_tx_count_per_block: f64,
+    #[prost(uint32, tag = "6")]
+    pub first_block_time: u32,
+    #[prost(uint32, tag = "7")]
+    pub last_block_time: u32,
+    #[prost(bytes = "vec", tag = "8")]
+    pub chain_work: ::prost::alloc::vec::Vec<u8>,
+}
+/// swagger:model DataPoint
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DataPoint {
+    #[prost(uint32, tag = "1")]
+    pub timestamp: u32,
+    #[prost(uint64, tag = "2")]
+    pub tx_count: u64,
+}
+/// swagger:model BlockDataPoints
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockDataPoints {
+    #[prost(message, repeated, tag = "1")]
+    pub data_points: ::prost::alloc::vec::Vec<DataPoint>,
+}
+/// swagger:model ChainTip
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChainTip {
+    /// Height of the chain tip
+    #[prost(uint32, tag = "1")]
+    pub height: u32,
+    /// Block hash of the tip
+    #[prost(string, tag = "2")]
+    pub hash: ::prost::alloc::string::String,
+    /// 0 for main chain, length of branch for forks
+    #[prost(uint32, tag = "3")]
+    pub branchlen: u32,
+    /// "active" for main chain, or "valid-fork", "valid-headers", "headers-only", "invalid"
+    #[prost(string, tag = "4")]
+    pub status: ::prost::alloc::string::String,
+}
+/// swagger:enum NotificationType
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NotificationType {
+    Ping = 0,
+    Subtree = 1,
+    Block = 2,
+    /// Deprecated, was previously used for MiningOn
+    NotUsed = 3,
+    FsmState = 4,
+    BlockSubtreesSet = 5,
+    /// Peer failed to provide data (catchup, subtree, block, etc)
+    PeerFailure = 6,
+}
+impl NotificationType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Ping => "PING",
+            Self::Subtree => "Subtree",
+            Self::Block => "Block",
+            Self::NotUsed => "NotUsed",
+            Self::FsmState => "FSMState",
+            Self::BlockSubtreesSet => "BlockSubtreesSet",
+            Self::PeerFailure => "PeerFailure",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PING" => Some(Self::Ping),
+            "Subtree" => Some(Self::Subtree),
+            "Block" => Some(Self::Block),
+            "NotUsed" => Some(Self::NotUsed),
+            "FSMState" => Some(Self::FsmState),
+            "BlockSubtreesSet" => Some(Self::BlockSubtreesSet),
+            "PeerFailure" => Some(Self::PeerFailure),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Retry policy for idempotent RPCs
+//!
+//! Read-only queries can safely be repeated when the node is briefly
+//! unreachable, overloaded or too slow to answer within the deadline.
+//! Mutating calls (bans, FSM events, peer connections) are never retried,
+//! since a request that timed out on the client may still have been applied
+//! by the server.
+
+use crate::error::{self, TeranodeError};
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+use tonic::{Code, Status};
+use tracing::warn;
+
+/// How failed idempotent requests are retried
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+
+    /// Upper bound on the delay between retries
+    pub max_backoff: Duration,
+
+    /// Factor the delay is multiplied by after each failed attempt
+    ///
+    /// Should be at least 1.0; [`with_multiplier`](Self::with_multiplier)
+    /// enforces this.
+    pub multiplier: f64,
+
+    /// Fraction of each delay that is randomised, between 0.0 and 1.0
+    ///
+    /// A delay `d` with jitter `j` is drawn uniformly from `[d * (1 - j), d]`,
+    /// which stops many clients from retrying in lockstep.
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: 0.2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that makes a single attempt and never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a failed call with this status is worth retrying
+    pub fn is_retryable(status: &Status) -> bool {
+        matches!(
+            status.code(),
+            Code::Unavailable | Code::Aborted | Code::ResourceExhausted | Code::DeadlineExceeded
+        )
+    }
+
+    /// Set the factor the delay is multiplied by after each failed attempt
+    ///
+    /// Fails if `multiplier` is NaN or below 1.0, since the delay must not shrink.
+    pub fn with_multiplier(mut self, multiplier: f64) -> error::Result<Self> {
+        if multiplier.is_nan() || multiplier < 1.0 {
+            return Err(TeranodeError::InvalidArgument(format!(
+                "Retry backoff multiplier must be at least 1.0, got {}",
+                multiplier
+            )));
+        }
+        self.multiplier = multiplier;
+        Ok(self)
+    }
+
+    /// Delay before retry number `retry` (starting at 1), without jitter
+    fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let secs = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        clamp_delay(secs, self.max_backoff)
+    }
+
+    /// Apply jitter to `delay` using a random sample in `[0, 1)`
+    fn jittered(&self, delay: Duration, sample: f64) -> Duration {
+        // A jitter set directly on the field may be NaN, which clamp keeps
+        let jitter = if self.jitter.is_nan() {
+            0.0
+        } else {
+            self.jitter.clamp(0.0, 1.0)
+        };
+        delay.mul_f64(1.0 - jitter * sample)
+    }
+
+    /// Run `call` until it succeeds, fails with a non-retryable status, or
+    /// the attempt budget is exhausted
+    pub(crate) async fn retry<T, F, Fut>(&self, mut call: F) -> Result<T, Status>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Status>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match call().await {
+                Err(status) if attempt < self.max_attempts && Self::is_retryable(&status) => {
+                    let delay = self.jittered(self.backoff(attempt), random_unit());
+                    warn!(
+                        "Request attempt {} failed: {}, retrying in {:?}",
+                        attempt, status, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Convert a delay in seconds to a `Duration` of at most `max`
+///
+/// Clamps before converting: a growing delay overflows `Duration` after
+/// enough attempts, and a multiplier set directly on a public field may be
+/// negative or NaN. NaN is treated as `max`.
+pub(crate) fn clamp_delay(secs: f64, max: Duration) -> Duration {
+    let max = max.as_secs_f64();
+    let secs = if secs.is_nan() {
+        max
+    } else {
+        secs.clamp(0.0, max)
+    };
+    Duration::from_secs_f64(secs)
+}
+
+/// A random number in `[0, 1)`, good enough for spreading out retries
+fn random_unit() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_backoff_delays() {
+        let policy = RetryPolicy {
+            max_attempts: 6,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: 0.5,
+        };
+
+        let delays: Vec<u64> = (1..=5).map(|n| policy.backoff(n).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 5, 5]);
+
+        let delay = Duration::from_secs(4);
+        assert_eq!(policy.jittered(delay, 0.0), delay);
+        assert_eq!(policy.jittered(delay, 1.0), Duration::from_secs(2));
+        assert!((0.0..1.0).contains(&random_unit()));
+
+        for jitter in [f64::NAN, -1.0, 2.0] {
+            let policy = RetryPolicy {
+                jitter,
+                ..policy.clone()
+            };
+            assert!(policy.jittered(delay, 1.0) <= delay);
+        }
+    }
+
+    #[test]
+    fn test_backoff_never_overflows() {
+        let policy = RetryPolicy {
+            max_attempts: u32::MAX,
+            ..RetryPolicy::default()
+        };
+        for retry in [60, 1_000, u32::MAX] {
+            assert_eq!(policy.backoff(retry), policy.max_backoff);
+        }
+
+        // Bad multipliers set on the field still yield a bounded delay
+        for multiplier in [f64::NAN, -2.0, f64::INFINITY] {
+            let policy = RetryPolicy {
+                multiplier,
+                ..policy.clone()
+            };
+            assert!(policy.backoff(3) <= policy.max_backoff);
+        }
+
+        assert!(RetryPolicy::default().with_multiplier(f64::NAN).is_err());
+        assert!(RetryPolicy::default().with_multiplier(0.5).is_err());
+        let policy = RetryPolicy::default().with_multiplier(1.0).unwrap();
+        assert_eq!(policy.backoff(10), policy.initial_backoff);
+    }
+
+    #[tokio::test]
+    async fn test_retry_only_retryable_statuses() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        };
+
+        let calls = AtomicU32::new(0);
+        let result: Result<(), Status> = policy
+            .retry(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(Status::unavailable("down"))
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        let calls = AtomicU32::new(0);
+        let result: Result<(), Status> = policy
+            .retry(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(Status::invalid_argument("bad"))
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Enough attempts to overflow an unclamped backoff
+        let policy = RetryPolicy {
+            max_attempts: 100,
+            initial_backoff: Duration::from_micros(1),
+            max_backoff: Duration::from_micros(10),
+            ..RetryPolicy::default()
+        };
+        let calls = AtomicU32::new(0);
+        let result: Result<(), Status> = policy
+            .retry(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(Status::unavailable("down"))
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 100);
+    }
+}
@@ -0,0 +1,355 @@
+//! Block statistics and time series
+//!
+//! Typed forms of GetBlockStats, GetBlockGraphData, GetLastNBlocks and
+//! GetLastNInvalidBlocks, with the protobuf's Unix seconds and timestamps
+//! converted to [`SystemTime`]. [`tx_per_second`] and [`block_intervals`] turn
+//! per-block data points into series ready to plot.
+
+use crate::error::{Result, TeranodeError};
+use crate::header::{decode_header, decode_timestamp, unix_time};
+use crate::proto::model::{
+    BlockInfo as ProtoBlockInfo, BlockStats as ProtoBlockStats, DataPoint as ProtoDataPoint,
+};
+use crate::work::ChainWork;
+use bitcoinsv::bitcoin::{BlockHash, BlockHeader};
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Summary statistics for the current chain
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockStats {
+    /// Number of blocks
+    pub block_count: u64,
+
+    /// Number of transactions across all blocks
+    pub tx_count: u64,
+
+    /// Height of the highest block
+    pub max_height: u64,
+
+    /// Average block size in bytes
+    pub avg_block_size: f64,
+
+    /// Average number of transactions per block
+    pub avg_tx_count_per_block: f64,
+
+    /// Timestamp of the earliest block
+    pub first_block_time: SystemTime,
+
+    /// Timestamp of the latest block
+    pub last_block_time: SystemTime,
+
+    /// Accumulated chain work of the tip
+    pub chain_work: ChainWork,
+}
+
+impl BlockStats {
+    /// Time between the first and last block
+    pub fn span(&self) -> Duration {
+        self.last_block_time
+            .duration_since(self.first_block_time)
+            .unwrap_or_default()
+    }
+
+    /// Average transactions per second over [`span`](Self::span), if it is not empty
+    pub fn tx_per_second(&self) -> Option<f64> {
+        let span = self.span().as_secs_f64();
+        (span > 0.0).then(|| self.tx_count as f64 / span)
+    }
+}
+
+impl TryFrom<ProtoBlockStats> for BlockStats {
+    type Error = TeranodeError;
+
+    fn try_from(stats: ProtoBlockStats) -> Result<Self> {
+        Ok(Self {
+            block_count: stats.block_count,
+            tx_count: stats.tx_count,
+            max_height: stats.max_height,
+            avg_block_size: stats.avg_block_size,
+            avg_tx_count_per_block: stats.avg_tx_count_per_block,
+            first_block_time: unix_time(stats.first_block_time),
+            last_block_time: unix_time(stats.last_block_time),
+            chain_work: ChainWork::from_be_bytes(&stats.chain_work)?,
+        })
+    }
+}
+
+/// Transaction count of one block, as returned by GetBlockGraphData
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataPoint {
+    /// Block timestamp
+    pub time: SystemTime,
+
+    /// Number of transactions in the block
+    pub tx_count: u64,
+}
+
+impl From<ProtoDataPoint> for DataPoint {
+    fn from(point: ProtoDataPoint) -> Self {
+        Self {
+            time: unix_time(point.timestamp),
+            tx_count: point.tx_count,
+        }
+    }
+}
+
+impl From<&BlockInfo> for DataPoint {
+    fn from(block: &BlockInfo) -> Self {
+        Self {
+            time: block.time(),
+            tx_count: block.transaction_count,
+        }
+    }
+}
+
+/// A recent block, as returned by GetLastNBlocks and GetLastNInvalidBlocks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockInfo {
+    /// The block header
+    pub header: BlockHeader,
+
+    /// Block height
+    pub height: u32,
+
+    /// When Teranode first saw the block, if recorded
+    pub seen_at: Option<SystemTime>,
+
+    /// Whether the block is not on the active chain
+    pub orphaned: bool,
+
+    /// Miner identifier, as extracted from the coinbase
+    pub miner: String,
+
+    /// Total value of the coinbase outputs in satoshis
+    pub coinbase_value: u64,
+
+    /// Number of transactions in the block, including the coinbase
+    pub transaction_count: u64,
+
+    /// Size of the block in bytes
+    pub size: u64,
+}
+
+impl BlockInfo {
+    /// Hash of the block
+    pub fn hash(&self) -> BlockHash {
+        self.header.hash()
+    }
+
+    /// Block timestamp from the header
+    pub fn time(&self) -> SystemTime {
+        unix_time(self.header.timestamp())
+    }
+}
+
+impl TryFrom<ProtoBlockInfo> for BlockInfo {
+    type Error = TeranodeError;
+
+    fn try_from(info: ProtoBlockInfo) -> Result<Self> {
+        Ok(Self {
+            header: decode_header(&info.block_header)?,
+            height: info.height,
+            seen_at: info.seen_at.as_ref().map(decode_timestamp),
+            orphaned: info.orphaned,
+            miner: info.miner,
+            coinbase_value: info.coinbase_value,
+            transaction_count: info.transaction_count,
+            size: info.size,
+        })
+    }
+}
+
+/// One value of a time series
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesPoint {
+    pub time: SystemTime,
+    pub value: f64,
+}
+
+/// Most buckets [`tx_per_second`] fills in between data points
+pub const MAX_SERIES_BUCKETS: usize = 100_000;
+
+/// Transactions per second, in buckets of width `bucket`
+///
+/// Buckets are aligned to multiples of `bucket` since the Unix epoch, so
+/// repeated calls over overlapping data produce matching buckets. Each point
+/// is labelled with the start of its bucket. Every bucket from the first to
+/// the last data point is present, with empty ones reporting zero, unless
+/// that would take more than [`MAX_SERIES_BUCKETS`]; then only buckets
+/// holding data points are returned.
+///
+/// # Panics
+/// If `bucket` is zero
+pub fn tx_per_second(points: &[DataPoint], bucket: Duration) -> Vec<SeriesPoint> {
+    assert!(!bucket.is_zero(), "bucket width must not be zero");
+    let width = bucket.as_nanos();
+
+    let mut totals = BTreeMap::new();
+    for point in points {
+        let since_epoch = point.time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        *totals.entry(since_epoch.as_nanos() / width).or_insert(0u64) += point.tx_count;
+    }
+    let (Some(&first), Some(&last)) = (totals.keys().next(), totals.keys().next_back()) else {
+        return Vec::new();
+    };
+    if last - first < MAX_SERIES_BUCKETS as u128 {
+        for i in first..last {
+            totals.entry(i).or_insert(0);
+        }
+    }
+
+    let seconds = bucket.as_secs_f64();
+    totals
+        .into_iter()
+        .map(|(i, total)| SeriesPoint {
+            time: UNIX_EPOCH + Duration::from_nanos((i * width) as u64),
+            value: total as f64 / seconds,
+        })
+        .collect()
+}
+
+/// Seconds since the previous block, labelled with each block's time
+///
+/// The points are ordered by time first, so the first block has no entry.
+pub fn block_intervals(points: &[DataPoint]) -> Vec<SeriesPoint> {
+    let mut times: Vec<SystemTime> = points.iter().map(|p| p.time).collect();
+    times.sort();
+    times
+        .windows(2)
+        .map(|pair| SeriesPoint {
+            time: pair[1],
+            value: pair[1]
+                .duration_since(pair[0])
+                .unwrap_or_default()
+                .as_secs_f64(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MockTeranode, MOCK_BLOCK_INTERVAL};
+    use crate::TeranodeClient;
+
+    fn point(seconds: u32, tx_count: u64) -> DataPoint {
+        DataPoint {
+            time: unix_time(seconds),
+            tx_count,
+        }
+    }
+
+    #[test]
+    fn test_tx_per_second() {
+        let points = [point(130, 60), point(10, 30), point(50, 30), point(70, 6)];
+        let series = tx_per_second(&points, Duration::from_secs(60));
+        let values: Vec<f64> = series.iter().map(|p| p.value).collect();
+        assert_eq!(values, vec![1.0, 0.1, 1.0]);
+        assert_eq!(series[0].time, unix_time(0));
+        assert_eq!(series[2].time, unix_time(120));
+
+        assert!(tx_per_second(&[], Duration::from_secs(60)).is_empty());
+
+        // A tiny bucket over a long span only reports the buckets with data
+        let points = [point(0, 1), point(365 * 24 * 3600, 1)];
+        let series = tx_per_second(&points, Duration::from_millis(1));
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[1].time, unix_time(365 * 24 * 3600));
+    }
+
+    #[test]
+    fn test_block_intervals() {
+        let points = [point(1_500, 1), point(1_000, 1), point(1_600, 1)];
+        let series = block_intervals(&points);
+        assert_eq!(
+            series,
+            vec![
+                SeriesPoint {
+                    time: unix_time(1_500),
+                    value: 500.0
+                },
+                SeriesPoint {
+                    time: unix_time(1_600),
+                    value: 100.0
+                },
+            ]
+        );
+        assert!(block_intervals(&points[..1]).is_empty());
+    }
+
+    #[test]
+    fn test_block_stats_from_proto() {
+        let stats = BlockStats::try_from(ProtoBlockStats {
+            block_count: 10,
+            tx_count: 1_200,
+            first_block_time: 1_000,
+            last_block_time: 1_600,
+            chain_work: vec![0x01, 0x00],
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(stats.span(), Duration::from_secs(600));
+        assert_eq!(stats.tx_per_second(), Some(2.0));
+        assert_eq!(stats.chain_work.to_string(), "256");
+
+        let empty = BlockStats::try_from(ProtoBlockStats::default()).unwrap();
+        assert_eq!(empty.tx_per_second(), None);
+    }
+
+    #[tokio::test]
+    async fn test_stats_against_mock() {
+        let mock = MockTeranode::with_blocks(5);
+        mock.fork(3, 1);
+        let server = mock.serve().await.unwrap();
+        let mut client = TeranodeClient::connect(server.endpoint()).await.unwrap();
+        let tip = mock.tip();
+
+        let stats = client.get_block_stats().await.unwrap();
+        assert_eq!(stats.block_count, 6);
+        assert_eq!(stats.max_height, 5);
+        assert_eq!(
+            stats.last_block_time,
+            unix_time(tip.block.header.timestamp())
+        );
+        assert_eq!(
+            stats.span(),
+            Duration::from_secs(5 * MOCK_BLOCK_INTERVAL as u64)
+        );
+
+        // Mock block timestamps are far in the past
+        let age = SystemTime::now()
+            .duration_since(stats.first_block_time)
+            .unwrap();
+        let points = client
+            .get_block_graph_data(age + Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert_eq!(points.len(), 6);
+        let intervals = block_intervals(&points);
+        assert!(intervals
+            .iter()
+            .all(|p| p.value == MOCK_BLOCK_INTERVAL as f64));
+
+        let blocks = client.get_last_n_blocks(3, false, None).await.unwrap();
+        let heights: Vec<u32> = blocks.iter().map(|b| b.height).collect();
+        assert_eq!(heights, vec![5, 4, 3]);
+        assert_eq!(blocks[0].hash(), tip.hash());
+        assert_eq!(blocks[0].coinbase_value, 50 * 100_000_000);
+        assert!(blocks[0].seen_at.is_some());
+
+        let blocks = client.get_last_n_blocks(3, true, Some(4)).await.unwrap();
+        assert_eq!(blocks.iter().filter(|b| b.orphaned).count(), 1);
+        assert!(blocks.iter().all(|b| b.height <= 4));
+
+        assert!(client
+            .get_last_n_invalid_blocks(5)
+            .await
+            .unwrap()
+            .is_empty());
+        client.invalidate_block(&tip.hash()).await.unwrap();
+        let invalid = client.get_last_n_invalid_blocks(5).await.unwrap();
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].hash(), tip.hash());
+    }
+}
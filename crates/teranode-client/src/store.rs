@@ -0,0 +1,601 @@
+//! Persistent header store
+//!
+//! [`HeaderStore`] caches headers on disk, keyed by hash and by height, so
+//! repeated lookups of historical headers don't have to go to the blockchain
+//! service. Attach a store to a client with
+//! [`TeranodeClientBuilder::header_store`](crate::TeranodeClientBuilder::header_store),
+//! look headers up with [`TeranodeClient::get_stored_header`] and keep the
+//! store current with [`HeaderStore::follow`].
+//!
+//! Only what never changes for a block is cached: the header, its height and
+//! its chain work. Flags such as `invalid` or `mined_set` change as the node
+//! processes blocks, so [`TeranodeClient::get_block_header`] always asks the
+//! node for them.
+//!
+//! Every stored header can be looked up by hash, but only headers known to
+//! be on the active chain are indexed by height, so looking up a fork block
+//! never displaces the main-chain header at its height.
+//!
+//! The file is an append-only log of records, each a little-endian `u32`
+//! length followed by a flags byte, the 80-byte header, the little-endian
+//! height and 32 bytes of big-endian chain work. Only the index of file
+//! offsets is held in memory. When a header is stored again the newest
+//! record wins, and a record flagged as the tip drops heights above it from
+//! the index, as after a reorg to a shorter chain.
+
+use crate::client::TeranodeClient;
+use crate::error::{Result, TeranodeError};
+use crate::header::HeaderWithMeta;
+use crate::notification::SubscriptionEvent;
+use crate::proto::model::NotificationType;
+use crate::work::ChainWork;
+use bitcoinsv::bitcoin::{BlockHash, BlockHeader};
+use futures::StreamExt;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{File, OpenOptions};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::ops::RangeBounds;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Maximum number of ancestors fetched to reconnect a new tip to the store
+const MAX_REORG_DEPTH: u32 = 1000;
+
+/// Size of the length prefix in front of each record
+const LENGTH_SIZE: u64 = 4;
+
+/// Size of a record after its length prefix
+const RECORD_SIZE: usize = 1 + BlockHeader::SIZE as usize + 4 + 32;
+
+/// Record flag: the header was on the active chain when it was stored
+const ON_CHAIN: u8 = 0x01;
+
+/// Record flag: the header was the tip of the active chain when it was stored
+const TIP: u8 = 0x02;
+
+/// A header together with the parts of its metadata that never change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredHeader {
+    pub header: BlockHeader,
+
+    /// Block height
+    pub height: u32,
+
+    /// Accumulated chain work up to and including this block
+    pub chain_work: ChainWork,
+}
+
+impl StoredHeader {
+    /// Hash of the block header
+    pub fn hash(&self) -> BlockHash {
+        self.header.hash()
+    }
+}
+
+impl TryFrom<&HeaderWithMeta> for StoredHeader {
+    type Error = TeranodeError;
+
+    fn try_from(header: &HeaderWithMeta) -> Result<Self> {
+        Ok(Self {
+            header: header.header.clone(),
+            height: header.meta.height,
+            chain_work: ChainWork::from_be_bytes(&header.meta.chain_work)?,
+        })
+    }
+}
+
+/// Seekable byte storage backing a store
+trait Storage: Read + Write + Seek + Send {}
+
+impl<T: Read + Write + Seek + Send> Storage for T {}
+
+struct StoreInner {
+    storage: Box<dyn Storage>,
+    end: u64,
+    by_hash: HashMap<BlockHash, u64>,
+    by_height: BTreeMap<u32, BlockHash>,
+}
+
+/// Header cache keyed by hash and active-chain height
+///
+/// Cloning is cheap; clones share the same underlying store.
+#[derive(Clone)]
+pub struct HeaderStore {
+    inner: Arc<Mutex<StoreInner>>,
+}
+
+impl HeaderStore {
+    /// Open the store at `path`, creating it if it doesn't exist
+    ///
+    /// A partially written record at the end of the file, as left by a crash,
+    /// is discarded. Any other record that fails to decode is an error, and
+    /// the file is left untouched.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path.as_ref())?;
+        Self::load(file)
+    }
+
+    /// Create a store that lives only in memory
+    pub fn in_memory() -> Self {
+        Self::from_storage(Box::new(Cursor::new(Vec::new())))
+    }
+
+    fn load(mut file: File) -> Result<Self> {
+        let len = file.metadata()?.len();
+        let mut index = Vec::new();
+        let mut end = 0;
+
+        let record_len = LENGTH_SIZE + RECORD_SIZE as u64;
+        let mut reader = std::io::BufReader::new(&mut file);
+        while len - end >= record_len {
+            let (header, flags) = read_record(&mut reader).map_err(|e| {
+                TeranodeError::DecodeError(format!(
+                    "Corrupt header record at offset {}: {}",
+                    end, e
+                ))
+            })?;
+            index.push((header.hash(), header.height, flags, end));
+            end += record_len;
+        }
+        drop(reader);
+        if end < len {
+            warn!("Discarding a partial header record of {} bytes", len - end);
+            file.set_len(end)?;
+        }
+
+        let store = Self::from_storage(Box::new(file));
+        {
+            let mut inner = store.lock();
+            inner.end = end;
+            for (hash, height, flags, offset) in index {
+                inner.index(hash, height, flags, offset);
+            }
+        }
+        Ok(store)
+    }
+
+    fn from_storage(storage: Box<dyn Storage>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(StoreInner {
+                storage,
+                end: 0,
+                by_hash: HashMap::new(),
+                by_height: BTreeMap::new(),
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StoreInner> {
+        self.inner.lock().expect("header store poisoned")
+    }
+
+    /// Number of distinct headers stored
+    pub fn len(&self) -> usize {
+        self.lock().by_hash.len()
+    }
+
+    /// Whether the store holds no headers
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether a header with this hash is stored
+    pub fn contains(&self, hash: &BlockHash) -> bool {
+        self.lock().by_hash.contains_key(hash)
+    }
+
+    /// Look up a header by hash, whether or not it is on the active chain
+    pub fn get(&self, hash: &BlockHash) -> Result<Option<StoredHeader>> {
+        let mut store = self.lock();
+        match store.by_hash.get(hash).copied() {
+            Some(offset) => store.read_at(offset).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Look up the active-chain header stored for `height`
+    pub fn get_by_height(&self, height: u32) -> Result<Option<StoredHeader>> {
+        let mut store = self.lock();
+        match store.by_height.get(&height).copied() {
+            Some(hash) => {
+                let offset = store.by_hash[&hash];
+                store.read_at(offset).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// The highest stored active-chain header
+    pub fn tip(&self) -> Result<Option<StoredHeader>> {
+        let height = self.lock().by_height.keys().next_back().copied();
+        match height {
+            Some(height) => self.get_by_height(height),
+            None => Ok(None),
+        }
+    }
+
+    /// Store a header by hash only
+    ///
+    /// Use this for headers that may be on a fork; they never replace the
+    /// active-chain header at their height.
+    pub fn insert(&self, header: &StoredHeader) -> Result<()> {
+        self.write(header, 0)
+    }
+
+    /// Store a header known to be on the active chain, replacing whatever
+    /// was stored for its height
+    pub fn insert_on_chain(&self, header: &StoredHeader) -> Result<()> {
+        self.write(header, ON_CHAIN)
+    }
+
+    /// Store the active chain's tip, dropping any heights above it
+    pub(crate) fn insert_tip(&self, header: &StoredHeader) -> Result<()> {
+        self.write(header, ON_CHAIN | TIP)
+    }
+
+    fn write(&self, header: &StoredHeader, flags: u8) -> Result<()> {
+        let mut store = self.lock();
+        let hash = header.hash();
+        if store.is_indexed(&hash, header.height, flags) {
+            return Ok(());
+        }
+        let offset = store.end;
+        let record = encode_record(header, flags);
+
+        store.storage.seek(SeekFrom::Start(offset))?;
+        store.storage.write_all(&record)?;
+        store.storage.flush()?;
+        store.end += record.len() as u64;
+        store.index(hash, header.height, flags, offset);
+        Ok(())
+    }
+
+    /// Fetch and store every active-chain header in a height range
+    ///
+    /// Useful to warm the store before running queries against it.
+    pub async fn fill(&self, client: &TeranodeClient, range: impl RangeBounds<u32>) -> Result<()> {
+        let mut headers = Box::pin(client.headers_stream(range));
+        while let Some(header) = headers.next().await {
+            self.insert_on_chain(&StoredHeader::try_from(&header?)?)?;
+        }
+        Ok(())
+    }
+
+    /// Keep the store updated from the node's Block notifications
+    ///
+    /// Each notification triggers a fetch of the node's best header, which is
+    /// stored as the tip along with any ancestors needed to reconnect it to
+    /// the stored chain after a reorg. Blocks announced on a fork are thus
+    /// never mistaken for the active chain. If the subscription drops,
+    /// headers above the stored tip are fetched to close the gap. The
+    /// returned task runs until aborted.
+    pub fn follow(&self, client: &TeranodeClient) -> Result<JoinHandle<()>> {
+        let mut client = client.clone();
+        let mut events = Box::pin(client.subscribe("header-store")?);
+        let store = self.clone();
+
+        Ok(tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                let result = match event {
+                    SubscriptionEvent::Notification(n)
+                        if n.notification_type == NotificationType::Block =>
+                    {
+                        store.connect(&mut client).await
+                    }
+                    SubscriptionEvent::Gap { .. } => store.catch_up(&mut client).await,
+                    SubscriptionEvent::Notification(_) => Ok(()),
+                };
+                if let Err(e) = result {
+                    warn!("Failed to update header store: {}", e);
+                }
+            }
+        }))
+    }
+
+    /// Store the node's best header as the tip, with any ancestors that
+    /// differ from the stored chain
+    async fn connect(&self, client: &mut TeranodeClient) -> Result<()> {
+        let best = HeaderWithMeta::try_from(client.get_best_block_header().await?)?;
+        let tip = StoredHeader::try_from(&best)?;
+        self.insert_tip(&tip)?;
+        debug!("Stored tip {} at height {}", tip.hash(), tip.height);
+
+        let mut next = tip;
+        for _ in 0..MAX_REORG_DEPTH {
+            let Some(parent_height) = next.height.checked_sub(1) else {
+                return Ok(());
+            };
+            let parent = next.header.prev_hash();
+            let stored = self.lock().by_height.get(&parent_height).copied();
+            match stored {
+                // Either linked up with the stored chain, or nothing stored
+                // below this height that could be stale
+                Some(stored) if stored == parent => return Ok(()),
+                None if self
+                    .lock()
+                    .by_height
+                    .range(..parent_height)
+                    .next()
+                    .is_none() =>
+                {
+                    return Ok(())
+                }
+                _ => {
+                    next = match self.get(&parent)? {
+                        Some(header) => header,
+                        None => StoredHeader::try_from(&client.fetch_block_header(&parent).await?)?,
+                    };
+                    self.insert_on_chain(&next)?;
+                    debug!("Stored header {} at height {}", parent, next.height);
+                }
+            }
+        }
+        Err(TeranodeError::InvalidArgument(format!(
+            "Best header {} does not connect to the stored chain within {} blocks",
+            best.hash(),
+            MAX_REORG_DEPTH
+        )))
+    }
+
+    /// Fetch headers above the stored tip, then reconnect to the best header
+    /// in case the chain reorganized while the subscription was down
+    async fn catch_up(&self, client: &mut TeranodeClient) -> Result<()> {
+        if let Some(tip) = self.tip()? {
+            self.fill(client, tip.height + 1..).await?;
+        }
+        self.connect(client).await
+    }
+}
+
+impl StoreInner {
+    fn index(&mut self, hash: BlockHash, height: u32, flags: u8, offset: u64) {
+        self.by_hash.insert(hash, offset);
+        if flags & TIP != 0 {
+            if let Some(above) = height.checked_add(1) {
+                self.by_height.split_off(&above);
+            }
+        }
+        if flags & ON_CHAIN != 0 {
+            self.by_height.insert(height, hash);
+        }
+    }
+
+    /// Whether writing this record would leave the index unchanged
+    fn is_indexed(&self, hash: &BlockHash, height: u32, flags: u8) -> bool {
+        if !self.by_hash.contains_key(hash) {
+            return false;
+        }
+        let top = self.by_height.last_key_value();
+        if flags & TIP != 0 && top != Some((&height, hash)) {
+            return false;
+        }
+        flags & ON_CHAIN == 0 || self.by_height.get(&height) == Some(hash)
+    }
+
+    fn read_at(&mut self, offset: u64) -> Result<StoredHeader> {
+        self.storage.seek(SeekFrom::Start(offset))?;
+        let (header, _) = read_record(&mut self.storage)?;
+        Ok(header)
+    }
+}
+
+fn encode_record(header: &StoredHeader, flags: u8) -> Vec<u8> {
+    let mut record = Vec::with_capacity(LENGTH_SIZE as usize + RECORD_SIZE);
+    record.extend_from_slice(&(RECORD_SIZE as u32).to_le_bytes());
+    record.push(flags);
+    record.extend_from_slice(&header.header.raw);
+    record.extend_from_slice(&header.height.to_le_bytes());
+    record.extend_from_slice(&header.chain_work.to_be_bytes());
+    record
+}
+
+/// Read one record, returning the stored header and its flags
+fn read_record(reader: &mut impl Read) -> Result<(StoredHeader, u8)> {
+    let mut len = [0u8; LENGTH_SIZE as usize];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len) as usize;
+    if len != RECORD_SIZE {
+        return Err(TeranodeError::DecodeError(format!(
+            "Header record has unexpected size: {} bytes",
+            len
+        )));
+    }
+
+    let mut record = [0u8; RECORD_SIZE];
+    reader.read_exact(&mut record)?;
+    let (flags, rest) = (record[0], &record[1..]);
+    let (header, rest) = rest.split_at(BlockHeader::SIZE as usize);
+    let (height, chain_work) = rest.split_at(4);
+    let header = StoredHeader {
+        header: BlockHeader::from_slice(header),
+        height: u32::from_le_bytes(height.try_into().expect("4 bytes")),
+        chain_work: ChainWork::from_be_bytes(chain_work)?,
+    };
+    Ok((header, flags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{mine_header, MockTeranode};
+    use bitcoinsv::bitcoin::Hash;
+    use std::time::Duration;
+
+    fn header_at(mock: &MockTeranode, height: u32) -> StoredHeader {
+        let block = mock.block_at(height).unwrap();
+        StoredHeader {
+            header: block.block.header,
+            height,
+            chain_work: ChainWork::from_be_bytes(&block.meta.chain_work).unwrap(),
+        }
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{}.dat", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_store_reopen() {
+        let path = temp_path("headers");
+        let mock = MockTeranode::with_blocks(3);
+
+        {
+            let store = HeaderStore::open(&path).unwrap();
+            for height in 0..=3 {
+                store.insert_on_chain(&header_at(&mock, height)).unwrap();
+            }
+        }
+
+        // Simulate a torn write at the end of the file
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(&[0xff, 0x00]).unwrap();
+        drop(file);
+
+        let store = HeaderStore::open(&path).unwrap();
+        assert_eq!(store.len(), 4);
+        assert_eq!(store.tip().unwrap().unwrap(), header_at(&mock, 3));
+        let hash = mock.block_at(2).unwrap().hash();
+        assert_eq!(store.get(&hash).unwrap().unwrap().height, 2);
+        assert_eq!(store.get_by_height(1).unwrap(), Some(header_at(&mock, 1)));
+        assert_eq!(store.get_by_height(9).unwrap(), None);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_store_rejects_corruption() {
+        let path = temp_path("headers-corrupt");
+        let mock = MockTeranode::with_blocks(2);
+        {
+            let store = HeaderStore::open(&path).unwrap();
+            for height in 0..=2 {
+                store.insert_on_chain(&header_at(&mock, height)).unwrap();
+            }
+        }
+
+        // Break the length prefix of the second record
+        let mut bytes = std::fs::read(&path).unwrap();
+        let len = bytes.len();
+        bytes[LENGTH_SIZE as usize + RECORD_SIZE] = 0xff;
+        std::fs::write(&path, &bytes).unwrap();
+
+        assert!(matches!(
+            HeaderStore::open(&path),
+            Err(TeranodeError::DecodeError(_))
+        ));
+        assert_eq!(std::fs::read(&path).unwrap().len(), len);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_store_truncates_above_tip() {
+        let path = temp_path("headers-reorg");
+        let mock = MockTeranode::with_blocks(4);
+        let parent = header_at(&mock, 1);
+        let timestamp = parent.header.timestamp() + 1;
+        let header = mine_header(&parent.hash(), &Hash::ZERO, timestamp);
+        let fork = header.hash();
+
+        {
+            let store = HeaderStore::open(&path).unwrap();
+            for height in 0..=4 {
+                store.insert_on_chain(&header_at(&mock, height)).unwrap();
+            }
+            let mut shorter = header_at(&mock, 2);
+            shorter.header = header;
+            store.insert_tip(&shorter).unwrap();
+
+            assert_eq!(store.tip().unwrap().unwrap().hash(), fork);
+            assert_eq!(store.get_by_height(3).unwrap(), None);
+        }
+
+        // The truncation survives a reopen, and the old blocks stay
+        // reachable by hash
+        let store = HeaderStore::open(&path).unwrap();
+        assert_eq!(store.tip().unwrap().unwrap().hash(), fork);
+        assert_eq!(store.get_by_height(4).unwrap(), None);
+        assert_eq!(store.get_by_height(1).unwrap(), Some(header_at(&mock, 1)));
+        let old_tip = mock.block_at(4).unwrap().hash();
+        assert_eq!(store.get(&old_tip).unwrap(), Some(header_at(&mock, 4)));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fork_lookup_keeps_chain_index() {
+        let mock = MockTeranode::with_blocks(5);
+        let server = mock.serve().await.unwrap();
+        let store = HeaderStore::in_memory();
+        let mut client = TeranodeClient::builder()
+            .blockchain_endpoint(server.endpoint())
+            .header_store(store.clone())
+            .connect()
+            .await
+            .unwrap();
+        store.fill(&client, 0..=5).await.unwrap();
+
+        let fork = mock.fork(3, 1)[0];
+        let header = client.get_block_header(&fork).await.unwrap();
+        assert_eq!(header.meta.height, 4);
+        assert!(store.contains(&fork));
+
+        assert_eq!(store.get_by_height(4).unwrap(), Some(header_at(&mock, 4)));
+        assert_eq!(
+            client.get_stored_header_by_height(4).await.unwrap(),
+            header_at(&mock, 4)
+        );
+        assert_eq!(store.tip().unwrap().unwrap(), header_at(&mock, 5));
+    }
+
+    #[tokio::test]
+    async fn test_store_serves_and_follows() {
+        let mock = MockTeranode::with_blocks(5);
+        let server = mock.serve().await.unwrap();
+        let store = HeaderStore::in_memory();
+        let mut client = TeranodeClient::builder()
+            .blockchain_endpoint(server.endpoint())
+            .header_store(store.clone())
+            .connect()
+            .await
+            .unwrap();
+
+        store.fill(&client, 0..=5).await.unwrap();
+        assert_eq!(store.len(), 6);
+
+        // Served from the store even when the node fails
+        mock.fail_next(tonic::Status::internal("boom"));
+        let header = client.get_stored_header_by_height(4).await.unwrap();
+        assert_eq!(header, header_at(&mock, 4));
+        let hash = header.hash();
+        assert_eq!(client.get_stored_header(&hash).await.unwrap().height, 4);
+        client.get_best_block_header().await.unwrap_err();
+
+        // Mutable flags always come from the node
+        mock.update_meta(4, |meta| meta.invalid = true);
+        assert!(client.get_block_header(&hash).await.unwrap().meta.invalid);
+
+        let task = store.follow(&client).unwrap();
+        let mut mined = None;
+        for _ in 0..100 {
+            let hash = mock.mine_blocks(1)[0];
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            if store.contains(&hash) {
+                mined = Some(hash);
+                break;
+            }
+        }
+        let mined = mined.expect("store never followed a mined block");
+        assert_eq!(store.tip().unwrap().unwrap().hash(), mined);
+        task.abort();
+    }
+}
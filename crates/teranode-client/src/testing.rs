@@ -0,0 +1,1547 @@
+//! In-process mock Teranode for tests
+//!
+//! [`MockTeranode`] implements the blockchain and peer gRPC services over a
+//! scriptable in-memory model: a canned chain of regtest-difficulty headers,
+//! a peer list, a ban list, key-value state, the FSM and a notification feed.
+//! [`MockTeranode::serve`] binds it to a local TCP socket so a real
+//! [`TeranodeClient`] (or the `tnode` binary) can talk to it, and
+//! [`serve_with_blocks`] does both in one step for client tests.
+//!
+//! Available to other crates with the `test-support` feature.
+//!
+//! RPCs the mock does not model return `UNIMPLEMENTED`.
+
+#![allow(clippy::result_large_err)] // service handlers return tonic::Status by design
+
+use crate::block::{encode_tx, Block};
+use crate::fsm::{FsmEvent, FsmState};
+use crate::header::{hash_bytes, BlockHeaderMeta};
+use crate::locator::locator_heights;
+use crate::proto::blockchain_api::blockchain_api_server::{BlockchainApi, BlockchainApiServer};
+use crate::proto::blockchain_api::*;
+use crate::proto::model::{
+    BlockDataPoints, BlockInfo, BlockStats, ChainTip, DataPoint, NotificationType, SuitableBlock,
+};
+use crate::proto::p2p_api::peer_service_server::{PeerService, PeerServiceServer};
+use crate::proto::p2p_api::*;
+use crate::TeranodeClient;
+use bitcoinsv::bitcoin::{BlockHash, BlockHeader, BlockchainId, FromHex, Hash, Tx};
+use futures::Stream;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::net::TcpListener;
+use tokio::sync::{broadcast, oneshot, watch};
+use tokio::task::JoinHandle;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+/// Difficulty bits used for mined mock blocks (regtest minimum difficulty)
+pub const MOCK_BITS: u32 = 0x207fffff;
+
+/// Seconds between the timestamps of consecutive mined mock blocks
+pub const MOCK_BLOCK_INTERVAL: u32 = 600;
+
+/// Block IDs of fork blocks start here, clear of the main chain's IDs
+const SIDE_BLOCK_ID_BASE: u32 = 1 << 20;
+
+/// Work contributed by a block at [`MOCK_BITS`]
+const MOCK_BLOCK_WORK: u128 = 2;
+
+// Coinbase transaction of the genesis block, reused for every mock block
+const GENESIS_COINBASE: &str = "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+
+/// A block held by the mock, with the metadata Teranode would store for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockBlock {
+    pub block: Block,
+    pub meta: BlockHeaderMeta,
+}
+
+impl MockBlock {
+    /// Hash of the block
+    pub fn hash(&self) -> BlockHash {
+        self.block.hash()
+    }
+
+    fn header_response(&self) -> GetBlockHeaderResponse {
+        GetBlockHeaderResponse {
+            block_header: self.block.header.raw.to_vec(),
+            id: self.meta.id,
+            height: self.meta.height,
+            tx_count: self.meta.tx_count,
+            size_in_bytes: self.meta.size_in_bytes,
+            miner: self.meta.miner.clone(),
+            peer_id: self.meta.peer_id.clone(),
+            block_time: self.meta.block_time,
+            timestamp: self.meta.timestamp,
+            chain_work: self.meta.chain_work.clone(),
+            mined_set: self.meta.mined_set,
+            subtrees_set: self.meta.subtrees_set,
+            invalid: self.meta.invalid,
+            processed_at: self.meta.processed_at.map(prost_types::Timestamp::from),
+        }
+    }
+
+    fn block_info(&self, orphaned: bool) -> BlockInfo {
+        BlockInfo {
+            seen_at: Some(prost_types::Timestamp {
+                seconds: self.meta.timestamp as i64,
+                nanos: 0,
+            }),
+            height: self.meta.height,
+            orphaned,
+            block_header: self.block.header.raw.to_vec(),
+            miner: self.meta.miner.clone(),
+            coinbase_value: self.block.coinbase_tx.outputs.iter().map(|o| o.value).sum(),
+            transaction_count: self.meta.tx_count,
+            size: self.meta.size_in_bytes,
+        }
+    }
+
+    fn block_response(&self) -> GetBlockResponse {
+        GetBlockResponse {
+            header: self.block.header.raw.to_vec(),
+            height: self.block.height,
+            coinbase_tx: encode_tx(&self.block.coinbase_tx),
+            transaction_count: self.block.transaction_count,
+            subtree_hashes: self.block.subtree_hashes.iter().map(hash_bytes).collect(),
+            size_in_bytes: self.block.size_in_bytes,
+            id: self.meta.id,
+        }
+    }
+}
+
+/// Build a header on top of `prev_hash` that meets the [`MOCK_BITS`] target
+pub fn mine_header(prev_hash: &BlockHash, merkle_root: &Hash, timestamp: u32) -> BlockHeader {
+    let mut raw = Vec::with_capacity(BlockHeader::SIZE as usize);
+    raw.extend_from_slice(&1u32.to_le_bytes());
+    raw.extend_from_slice(&prev_hash.raw);
+    raw.extend_from_slice(&merkle_root.raw);
+    raw.extend_from_slice(&timestamp.to_le_bytes());
+    raw.extend_from_slice(&MOCK_BITS.to_le_bytes());
+    raw.extend_from_slice(&0u32.to_le_bytes());
+
+    for nonce in 0u32.. {
+        raw[76..80].copy_from_slice(&nonce.to_le_bytes());
+        let header = BlockHeader::from_slice(&raw);
+        // The target for MOCK_BITS is 0x7fffff followed by 29 zero bytes
+        if header.hash().raw[31] < 0x7f {
+            return header;
+        }
+    }
+    unreachable!("no nonce satisfies the mock target")
+}
+
+/// Accumulated work of a mock block
+fn chain_work(meta: &BlockHeaderMeta) -> u128 {
+    let work = &meta.chain_work[meta.chain_work.len().saturating_sub(16)..];
+    work.iter().fold(0, |acc, b| (acc << 8) | *b as u128)
+}
+
+/// Wrap a mined header in a single-transaction block with matching metadata
+fn mock_block(header: BlockHeader, height: u32, id: u32, parent_work: u128) -> MockBlock {
+    let coinbase_tx = Tx::from_hex(GENESIS_COINBASE).expect("valid coinbase");
+    let block = Block {
+        header: header.clone(),
+        coinbase_tx,
+        transaction_count: 1,
+        size_in_bytes: 285,
+        subtree_hashes: Vec::new(),
+        height,
+        id: Some(id),
+    };
+
+    let mut work = vec![0u8; 16];
+    work.extend_from_slice(&(parent_work + MOCK_BLOCK_WORK).to_be_bytes());
+    let meta = BlockHeaderMeta {
+        id,
+        height,
+        tx_count: 1,
+        size_in_bytes: 285,
+        miner: "/mock/".to_string(),
+        block_time: header.timestamp(),
+        timestamp: header.timestamp(),
+        chain_work: work,
+        mined_set: true,
+        subtrees_set: true,
+        ..Default::default()
+    };
+    MockBlock { block, meta }
+}
+
+/// How the mock should misbehave on an upcoming request
+enum Scripted {
+    Fail(Status),
+    Delay(Duration),
+}
+
+type NotificationStream = Pin<Box<dyn Stream<Item = Result<Notification, Status>> + Send>>;
+
+/// Mutable model behind the mock services
+#[derive(Default)]
+struct MockState {
+    chain: Vec<MockBlock>,
+    side: Vec<MockBlock>,
+    peers: Vec<Peer>,
+    banned: BTreeSet<String>,
+    state: HashMap<String, Vec<u8>>,
+    unhealthy: bool,
+    scripted: VecDeque<Scripted>,
+}
+
+struct Inner {
+    state: Mutex<MockState>,
+    fsm: watch::Sender<FsmState>,
+    notifications: broadcast::Sender<Notification>,
+    disconnect: broadcast::Sender<()>,
+}
+
+/// A scriptable in-memory Teranode
+///
+/// Cloning is cheap and every clone shares the same state, so a test can
+/// keep a handle to script the node while it is being served.
+#[derive(Clone)]
+pub struct MockTeranode {
+    inner: Arc<Inner>,
+}
+
+impl Default for MockTeranode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockTeranode {
+    /// Create a mock whose chain holds only the regtest genesis block
+    pub fn new() -> Self {
+        let (fsm, _) = watch::channel(FsmState::Idle);
+        let (notifications, _) = broadcast::channel(256);
+        let (disconnect, _) = broadcast::channel(4);
+
+        let mock = Self {
+            inner: Arc::new(Inner {
+                state: Mutex::new(MockState::default()),
+                fsm,
+                notifications,
+                disconnect,
+            }),
+        };
+        mock.push_header(BlockHeader::get_genesis(BlockchainId::Regtest));
+        mock
+    }
+
+    /// Create a mock with `count` blocks mined on top of genesis
+    pub fn with_blocks(count: u32) -> Self {
+        let mock = Self::new();
+        for _ in 0..count {
+            mock.push_header(mock.next_header());
+        }
+        mock
+    }
+
+    /// Mine `count` blocks on top of the tip, announcing each one to subscribers
+    pub fn mine_blocks(&self, count: u32) -> Vec<BlockHash> {
+        (0..count)
+            .map(|_| self.add_header(self.next_header()))
+            .collect()
+    }
+
+    /// Append a canned header to the chain and announce it to subscribers
+    ///
+    /// The header is not validated; tests may use this to serve broken chains.
+    pub fn add_header(&self, header: BlockHeader) -> BlockHash {
+        let hash = self.push_header(header);
+        self.notify(Notification {
+            r#type: NotificationType::Block as i32,
+            hash: hash_bytes(&hash),
+            ..Default::default()
+        });
+        hash
+    }
+
+    /// The block at the tip of the chain
+    pub fn tip(&self) -> MockBlock {
+        self.state()
+            .chain
+            .last()
+            .cloned()
+            .expect("chain always contains genesis")
+    }
+
+    /// The block at `height`, if the chain is that long
+    pub fn block_at(&self, height: u32) -> Option<MockBlock> {
+        self.state().chain.get(height as usize).cloned()
+    }
+
+    /// Modify the stored metadata of the block at `height`
+    pub fn update_meta(&self, height: u32, update: impl FnOnce(&mut BlockHeaderMeta)) {
+        if let Some(block) = self.state().chain.get_mut(height as usize) {
+            update(&mut block.meta);
+        }
+    }
+
+    /// Mine a competing branch of `count` blocks on top of the main-chain
+    /// block at `height`
+    ///
+    /// The branch is reported as a `valid-fork` chain tip (`invalid` once
+    /// invalidated) but never becomes the active chain. Returns the hashes of
+    /// the new blocks, oldest first.
+    pub fn fork(&self, height: u32, count: u32) -> Vec<BlockHash> {
+        let mut parent = self
+            .block_at(height)
+            .expect("fork point is on the main chain");
+        let mut hashes = Vec::new();
+        for _ in 0..count {
+            let mut state = self.state();
+            let id = SIDE_BLOCK_ID_BASE + state.side.len() as u32;
+            let header = mine_header(
+                &parent.hash(),
+                &Hash::sha256d(&id.to_le_bytes()),
+                parent.block.header.timestamp() + MOCK_BLOCK_INTERVAL + 1,
+            );
+            let block = mock_block(
+                header,
+                parent.block.height + 1,
+                id,
+                chain_work(&parent.meta),
+            );
+            hashes.push(block.hash());
+            state.side.push(block.clone());
+            parent = block;
+        }
+        hashes
+    }
+
+    /// Add a peer to the list returned by GetPeers
+    pub fn add_peer(&self, peer: Peer) {
+        self.state().peers.push(peer);
+    }
+
+    /// Addresses and subnets currently banned
+    pub fn banned(&self) -> Vec<String> {
+        self.state().banned.iter().cloned().collect()
+    }
+
+    /// Current FSM state
+    pub fn fsm_state(&self) -> FsmState {
+        *self.inner.fsm.borrow()
+    }
+
+    /// Move the FSM to `state`, announcing the transition to subscribers
+    pub fn set_fsm_state(&self, state: FsmState) {
+        self.inner.fsm.send_replace(state);
+        self.notify(Notification {
+            r#type: NotificationType::FsmState as i32,
+            metadata: Some(NotificationMetadata {
+                metadata: HashMap::from([("destination".to_string(), state.to_string())]),
+            }),
+            ..Default::default()
+        });
+    }
+
+    /// Send a notification to every subscriber
+    pub fn notify(&self, notification: Notification) {
+        // No receivers just means nobody is subscribed yet
+        let _ = self.inner.notifications.send(notification);
+    }
+
+    /// Drop every open Subscribe stream, as if the node had restarted
+    pub fn disconnect_subscribers(&self) {
+        let _ = self.inner.disconnect.send(());
+    }
+
+    /// Whether HealthGRPC reports the service as healthy, which it does by default
+    pub fn set_healthy(&self, healthy: bool) {
+        self.state().unhealthy = !healthy;
+    }
+
+    /// Make the next request fail with `status`
+    ///
+    /// Queued failures and delays are consumed one per request, in order.
+    pub fn fail_next(&self, status: Status) {
+        self.state().scripted.push_back(Scripted::Fail(status));
+    }
+
+    /// Make the next request wait for `delay` before it is answered
+    ///
+    /// Useful to simulate a node that hangs past the client's deadline.
+    pub fn delay_next(&self, delay: Duration) {
+        self.state().scripted.push_back(Scripted::Delay(delay));
+    }
+
+    /// Serve the blockchain and peer services on an ephemeral local port
+    pub async fn serve(&self) -> std::io::Result<MockServer> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let incoming = TcpIncoming::from_listener(listener, true, None)
+            .map_err(|e| std::io::Error::other(e.to_string()))?;
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+
+        let router = Server::builder()
+            .add_service(BlockchainApiServer::new(self.clone()))
+            .add_service(PeerServiceServer::new(self.clone()));
+        let handle = tokio::spawn(async move {
+            let _ = router
+                .serve_with_incoming_shutdown(incoming, async {
+                    let _ = shutdown_rx.await;
+                })
+                .await;
+        });
+
+        Ok(MockServer {
+            addr,
+            shutdown: Some(shutdown),
+            handle,
+        })
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.inner.state.lock().expect("mock state poisoned")
+    }
+
+    /// Consume a scripted failure or delay, if one is queued
+    async fn check(&self) -> Result<(), Status> {
+        let scripted = self.state().scripted.pop_front();
+        match scripted {
+            Some(Scripted::Fail(status)) => Err(status),
+            Some(Scripted::Delay(delay)) => {
+                tokio::time::sleep(delay).await;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Mine the header that would extend the current tip
+    fn next_header(&self) -> BlockHeader {
+        let tip = self.tip();
+        let height = tip.block.height + 1;
+        let merkle_root = Hash::sha256d(&height.to_le_bytes());
+        mine_header(
+            &tip.hash(),
+            &merkle_root,
+            tip.block.header.timestamp() + MOCK_BLOCK_INTERVAL,
+        )
+    }
+
+    fn push_header(&self, header: BlockHeader) -> BlockHash {
+        let mut state = self.state();
+        let height = state.chain.len() as u32;
+        let parent_work = state.chain.last().map_or(0, |b| chain_work(&b.meta));
+        let block = mock_block(header, height, height, parent_work);
+
+        let hash = block.hash();
+        state.chain.push(block);
+        hash
+    }
+
+    fn find(&self, hash: &[u8]) -> Result<MockBlock, Status> {
+        let state = self.state();
+        state
+            .chain
+            .iter()
+            .chain(&state.side)
+            .find(|b| b.hash().raw.as_slice() == hash)
+            .cloned()
+            .ok_or_else(|| Status::not_found("block not found"))
+    }
+
+    /// Walk back `depth` blocks from `hash`, following prev_hash links
+    fn ancestors(&self, hash: &[u8], depth: u64) -> Result<Vec<MockBlock>, Status> {
+        let mut block = self.find(hash)?;
+        let mut blocks = vec![block.clone()];
+        for _ in 1..depth {
+            if block.block.height == 0 {
+                break;
+            }
+            block = self.find(&block.block.header.prev_hash().raw)?;
+            blocks.push(block.clone());
+        }
+        Ok(blocks)
+    }
+
+    /// Set the invalid flag on a block and all of its descendants
+    ///
+    /// Only the flag changes; the active chain is not reorganized.
+    fn set_invalid(&self, hash: &[u8], invalid: bool) -> Result<Vec<BlockHash>, Status> {
+        let root = self.find(hash)?.hash();
+        let mut state = self.state();
+        let MockState { chain, side, .. } = &mut *state;
+
+        // Parents always precede their children in chain-then-side order
+        let mut affected = vec![root];
+        for block in chain.iter_mut().chain(side.iter_mut()) {
+            let hash = block.hash();
+            if hash == root || affected.contains(&block.block.header.prev_hash()) {
+                if hash != root {
+                    affected.push(hash);
+                }
+                block.meta.invalid = invalid;
+            }
+        }
+        Ok(affected)
+    }
+
+    /// Modify the stored metadata of a block on the chain or a fork
+    fn update_block(
+        &self,
+        hash: &[u8],
+        update: impl FnOnce(&mut BlockHeaderMeta),
+    ) -> Result<(), Status> {
+        let mut state = self.state();
+        let MockState { chain, side, .. } = &mut *state;
+        let block = chain
+            .iter_mut()
+            .chain(side.iter_mut())
+            .find(|b| b.hash().raw.as_slice() == hash)
+            .ok_or_else(|| Status::not_found("block not found"))?;
+        update(&mut block.meta);
+        Ok(())
+    }
+
+    /// Serialized blocks, from the chain and forks, whose metadata matches
+    fn blocks_where(&self, matches: impl Fn(&BlockHeaderMeta) -> bool) -> Vec<Vec<u8>> {
+        let state = self.state();
+        state
+            .chain
+            .iter()
+            .chain(&state.side)
+            .filter(|b| matches(&b.meta))
+            .map(|b| b.block.to_bytes())
+            .collect()
+    }
+
+    fn range(&self, start: u32, end: u32) -> Vec<MockBlock> {
+        let state = self.state();
+        let end = (end as usize + 1).min(state.chain.len());
+        state
+            .chain
+            .get(start as usize..end)
+            .map(<[MockBlock]>::to_vec)
+            .unwrap_or_default()
+    }
+
+    fn transition(&self, event: FsmEvent) -> FsmState {
+        let state = match event {
+            FsmEvent::Stop => FsmState::Idle,
+            FsmEvent::Run => FsmState::Running,
+            FsmEvent::CatchUpBlocks => FsmState::CatchingBlocks,
+            FsmEvent::LegacySync => FsmState::LegacySyncing,
+        };
+        self.set_fsm_state(state);
+        state
+    }
+
+    async fn wait_for_state(&self, accept: impl Fn(FsmState) -> bool) -> Result<(), Status> {
+        let mut rx = self.inner.fsm.subscribe();
+        rx.wait_for(|state| accept(*state))
+            .await
+            .map_err(|_| Status::unavailable("mock shut down"))?;
+        Ok(())
+    }
+}
+
+/// A running mock server, shut down when dropped
+pub struct MockServer {
+    addr: SocketAddr,
+    shutdown: Option<oneshot::Sender<()>>,
+    handle: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Socket address the server is listening on
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Endpoint URL to pass to [`TeranodeClient::connect`](crate::TeranodeClient::connect)
+    pub fn endpoint(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Stop the server and wait for it to finish
+    pub async fn shutdown(mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        let _ = (&mut self.handle).await;
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// Serve a mock with `count` blocks and connect a client to both of its services
+///
+/// The client only works while the returned server is alive, so keep it
+/// bound for the rest of the test. Panics if the mock cannot be served.
+pub async fn serve_with_blocks(count: u32) -> (MockTeranode, MockServer, TeranodeClient) {
+    let mock = MockTeranode::with_blocks(count);
+    let server = mock.serve().await.expect("mock server starts");
+    let endpoint = Some(server.endpoint());
+    let client = TeranodeClient::connect_with_endpoints(endpoint.clone(), endpoint)
+        .await
+        .expect("client connects to the mock");
+    (mock, server, client)
+}
+
+fn headers_response(blocks: &[MockBlock]) -> (Vec<Vec<u8>>, Vec<Vec<u8>>) {
+    blocks
+        .iter()
+        .map(|b| (b.block.header.raw.to_vec(), b.meta.to_bytes()))
+        .unzip()
+}
+
+fn unimplemented<T>(rpc: &str) -> Result<Response<T>, Status> {
+    Err(Status::unimplemented(format!(
+        "{} is not supported by the mock",
+        rpc
+    )))
+}
+
+#[tonic::async_trait]
+impl BlockchainApi for MockTeranode {
+    async fn health_grpc(&self, _: Request<()>) -> Result<Response<HealthResponse>, Status> {
+        self.check().await?;
+        // Same shape as Teranode's dependency report
+        let ok = !self.state().unhealthy;
+        let status = if ok { "200" } else { "503" };
+        let details = format!(
+            r#"{{"status":"{status}","dependencies":[{{"resource":"MockStore","status":"{status}","error":"<nil>","message":"mock"}}]}}"#
+        );
+        Ok(Response::new(HealthResponse {
+            ok,
+            details,
+            timestamp: Some(SystemTime::now().into()),
+        }))
+    }
+
+    async fn add_block(&self, _: Request<AddBlockRequest>) -> Result<Response<()>, Status> {
+        unimplemented("AddBlock")
+    }
+
+    async fn get_block(
+        &self,
+        request: Request<GetBlockRequest>,
+    ) -> Result<Response<GetBlockResponse>, Status> {
+        self.check().await?;
+        let block = self.find(&request.into_inner().hash)?;
+        Ok(Response::new(block.block_response()))
+    }
+
+    async fn get_blocks(
+        &self,
+        request: Request<GetBlocksRequest>,
+    ) -> Result<Response<GetBlocksResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        let start = self.find(&request.hash)?.block.height;
+        let end = start.saturating_add(request.count.saturating_sub(1));
+        Ok(Response::new(GetBlocksResponse {
+            blocks: self
+                .range(start, end)
+                .iter()
+                .map(|b| b.block.to_bytes())
+                .collect(),
+        }))
+    }
+
+    async fn get_block_by_height(
+        &self,
+        request: Request<GetBlockByHeightRequest>,
+    ) -> Result<Response<GetBlockResponse>, Status> {
+        self.check().await?;
+        self.block_at(request.into_inner().height)
+            .map(|b| Response::new(b.block_response()))
+            .ok_or_else(|| Status::not_found("block not found"))
+    }
+
+    async fn get_block_by_id(
+        &self,
+        request: Request<GetBlockByIdRequest>,
+    ) -> Result<Response<GetBlockResponse>, Status> {
+        self.check().await?;
+        let id = request.into_inner().id;
+        u32::try_from(id)
+            .ok()
+            .and_then(|id| self.block_at(id))
+            .map(|b| Response::new(b.block_response()))
+            .ok_or_else(|| Status::not_found("block not found"))
+    }
+
+    async fn get_next_block_id(
+        &self,
+        _: Request<()>,
+    ) -> Result<Response<GetNextBlockIdResponse>, Status> {
+        self.check().await?;
+        Ok(Response::new(GetNextBlockIdResponse {
+            next_block_id: self.state().chain.len() as u64,
+        }))
+    }
+
+    async fn get_block_stats(&self, _: Request<()>) -> Result<Response<BlockStats>, Status> {
+        self.check().await?;
+        let state = self.state();
+        let chain = &state.chain;
+        let tip = chain.last().expect("chain always contains genesis");
+        let tx_count: u64 = chain.iter().map(|b| b.meta.tx_count).sum();
+        let size: u64 = chain.iter().map(|b| b.meta.size_in_bytes).sum();
+        let count = chain.len() as u64;
+        Ok(Response::new(BlockStats {
+            block_count: count,
+            tx_count,
+            max_height: tip.meta.height as u64,
+            avg_block_size: size as f64 / count as f64,
+            avg_tx_count_per_block: tx_count as f64 / count as f64,
+            first_block_time: chain[0].block.header.timestamp(),
+            last_block_time: tip.block.header.timestamp(),
+            chain_work: tip.meta.chain_work.clone(),
+        }))
+    }
+
+    async fn get_block_graph_data(
+        &self,
+        request: Request<GetBlockGraphDataRequest>,
+    ) -> Result<Response<BlockDataPoints>, Status> {
+        self.check().await?;
+        // Main-chain blocks stored within the period
+        let period = Duration::from_millis(request.into_inner().period_millis);
+        let since = SystemTime::now()
+            .checked_sub(period)
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_secs());
+        let data_points = self
+            .state()
+            .chain
+            .iter()
+            .filter(|b| b.meta.timestamp as u64 >= since)
+            .map(|b| DataPoint {
+                timestamp: b.meta.timestamp,
+                tx_count: b.meta.tx_count,
+            })
+            .collect();
+        Ok(Response::new(BlockDataPoints { data_points }))
+    }
+
+    async fn get_last_n_blocks(
+        &self,
+        request: Request<GetLastNBlocksRequest>,
+    ) -> Result<Response<GetLastNBlocksResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        let state = self.state();
+        let side = state.side.iter().filter(|_| request.include_orphans);
+        let mut blocks: Vec<BlockInfo> = state
+            .chain
+            .iter()
+            .map(|b| b.block_info(false))
+            .chain(side.map(|b| b.block_info(true)))
+            .filter(|b| request.from_height == 0 || b.height <= request.from_height)
+            .collect();
+        blocks.sort_by_key(|b| std::cmp::Reverse(b.height));
+        blocks.truncate(request.number_of_blocks.max(0) as usize);
+        Ok(Response::new(GetLastNBlocksResponse { blocks }))
+    }
+
+    async fn get_last_n_invalid_blocks(
+        &self,
+        request: Request<GetLastNInvalidBlocksRequest>,
+    ) -> Result<Response<GetLastNInvalidBlocksResponse>, Status> {
+        self.check().await?;
+        let state = self.state();
+        let chain = state.chain.iter().map(|b| (b, false));
+        let side = state.side.iter().map(|b| (b, true));
+        let mut blocks: Vec<BlockInfo> = chain
+            .chain(side)
+            .filter(|(b, _)| b.meta.invalid)
+            .map(|(b, orphaned)| b.block_info(orphaned))
+            .collect();
+        blocks.sort_by_key(|b| std::cmp::Reverse(b.height));
+        blocks.truncate(request.into_inner().n.max(0) as usize);
+        Ok(Response::new(GetLastNInvalidBlocksResponse { blocks }))
+    }
+
+    async fn get_suitable_block(
+        &self,
+        request: Request<GetSuitableBlockRequest>,
+    ) -> Result<Response<GetSuitableBlockResponse>, Status> {
+        self.check().await?;
+        // The median by timestamp of the block and its two parents
+        let mut candidates = self.ancestors(&request.into_inner().hash, 3)?;
+        candidates.sort_by_key(|b| b.block.header.timestamp());
+        let block = &candidates[candidates.len() / 2];
+        Ok(Response::new(GetSuitableBlockResponse {
+            block: Some(SuitableBlock {
+                hash: hash_bytes(&block.hash()),
+                height: block.block.height,
+                n_bits: block.block.header.bits().to_le_bytes().to_vec(),
+                time: block.block.header.timestamp(),
+                chain_work: block.meta.chain_work.clone(),
+            }),
+        }))
+    }
+
+    async fn get_hash_of_ancestor_block(
+        &self,
+        request: Request<GetHashOfAncestorBlockRequest>,
+    ) -> Result<Response<GetHashOfAncestorBlockResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        let mut blocks = self.ancestors(&request.hash, request.depth as u64 + 1)?;
+        if blocks.len() as u64 != request.depth as u64 + 1 {
+            return Err(Status::not_found("ancestor not found"));
+        }
+        let ancestor = blocks.pop().expect("at least the starting block");
+        Ok(Response::new(GetHashOfAncestorBlockResponse {
+            hash: hash_bytes(&ancestor.hash()),
+        }))
+    }
+
+    async fn get_latest_block_header_from_block_locator(
+        &self,
+        request: Request<GetLatestBlockHeaderFromBlockLocatorRequest>,
+    ) -> Result<Response<GetBlockHeaderResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        let branch = self.ancestors(&request.best_block_hash, u64::MAX)?;
+        request
+            .block_locator_hashes
+            .iter()
+            .find_map(|hash| {
+                branch
+                    .iter()
+                    .find(|b| b.hash().raw.as_slice() == hash.as_slice())
+            })
+            .map(|b| Response::new(b.header_response()))
+            .ok_or_else(|| Status::not_found("no locator block on the chain"))
+    }
+
+    async fn get_block_headers_from_oldest(
+        &self,
+        _: Request<GetBlockHeadersFromOldestRequest>,
+    ) -> Result<Response<GetBlockHeadersResponse>, Status> {
+        unimplemented("GetBlockHeadersFromOldest")
+    }
+
+    async fn get_next_work_required(
+        &self,
+        request: Request<GetNextWorkRequiredRequest>,
+    ) -> Result<Response<GetNextWorkRequiredResponse>, Status> {
+        self.check().await?;
+        // Regtest difficulty never adjusts
+        self.find(&request.into_inner().previous_block_hash)?;
+        Ok(Response::new(GetNextWorkRequiredResponse {
+            bits: MOCK_BITS.to_le_bytes().to_vec(),
+        }))
+    }
+
+    async fn get_block_exists(
+        &self,
+        request: Request<GetBlockRequest>,
+    ) -> Result<Response<GetBlockExistsResponse>, Status> {
+        self.check().await?;
+        Ok(Response::new(GetBlockExistsResponse {
+            exists: self.find(&request.into_inner().hash).is_ok(),
+        }))
+    }
+
+    async fn get_block_headers(
+        &self,
+        request: Request<GetBlockHeadersRequest>,
+    ) -> Result<Response<GetBlockHeadersResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        let blocks = self.ancestors(&request.start_hash, request.number_of_headers)?;
+
+        let (block_headers, metas) = headers_response(&blocks);
+        Ok(Response::new(GetBlockHeadersResponse {
+            block_headers,
+            metas,
+        }))
+    }
+
+    async fn get_block_headers_to_common_ancestor(
+        &self,
+        _: Request<GetBlockHeadersToCommonAncestorRequest>,
+    ) -> Result<Response<GetBlockHeadersResponse>, Status> {
+        unimplemented("GetBlockHeadersToCommonAncestor")
+    }
+
+    async fn get_block_headers_from_common_ancestor(
+        &self,
+        _: Request<GetBlockHeadersFromCommonAncestorRequest>,
+    ) -> Result<Response<GetBlockHeadersResponse>, Status> {
+        unimplemented("GetBlockHeadersFromCommonAncestor")
+    }
+
+    async fn get_block_headers_from_till(
+        &self,
+        request: Request<GetBlockHeadersFromTillRequest>,
+    ) -> Result<Response<GetBlockHeadersResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        let start = self.find(&request.start_hash)?.block.height;
+        let end = self.find(&request.end_hash)?.block.height;
+
+        let (block_headers, metas) = headers_response(&self.range(start, end));
+        Ok(Response::new(GetBlockHeadersResponse {
+            block_headers,
+            metas,
+        }))
+    }
+
+    async fn get_block_headers_from_height(
+        &self,
+        request: Request<GetBlockHeadersFromHeightRequest>,
+    ) -> Result<Response<GetBlockHeadersFromHeightResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        if request.limit == 0 {
+            return Ok(Response::new(Default::default()));
+        }
+        let end = request.start_height.saturating_add(request.limit - 1);
+
+        let (block_headers, metas) = headers_response(&self.range(request.start_height, end));
+        Ok(Response::new(GetBlockHeadersFromHeightResponse {
+            block_headers,
+            metas,
+        }))
+    }
+
+    async fn get_block_headers_by_height(
+        &self,
+        request: Request<GetBlockHeadersByHeightRequest>,
+    ) -> Result<Response<GetBlockHeadersByHeightResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+
+        let blocks = self.range(request.start_height, request.end_height);
+        let (block_headers, metas) = headers_response(&blocks);
+        Ok(Response::new(GetBlockHeadersByHeightResponse {
+            block_headers,
+            metas,
+        }))
+    }
+
+    async fn get_blocks_by_height(
+        &self,
+        request: Request<GetBlocksByHeightRequest>,
+    ) -> Result<Response<GetBlocksByHeightResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        Ok(Response::new(GetBlocksByHeightResponse {
+            blocks: self
+                .range(request.start_height, request.end_height)
+                .iter()
+                .map(|b| b.block.to_bytes())
+                .collect(),
+        }))
+    }
+
+    async fn find_blocks_containing_subtree(
+        &self,
+        _: Request<FindBlocksContainingSubtreeRequest>,
+    ) -> Result<Response<FindBlocksContainingSubtreeResponse>, Status> {
+        unimplemented("FindBlocksContainingSubtree")
+    }
+
+    async fn get_block_header_i_ds(
+        &self,
+        _: Request<GetBlockHeadersRequest>,
+    ) -> Result<Response<GetBlockHeaderIDsResponse>, Status> {
+        unimplemented("GetBlockHeaderIDs")
+    }
+
+    async fn get_best_block_header(
+        &self,
+        _: Request<()>,
+    ) -> Result<Response<GetBlockHeaderResponse>, Status> {
+        self.check().await?;
+        Ok(Response::new(self.tip().header_response()))
+    }
+
+    async fn check_block_is_in_current_chain(
+        &self,
+        request: Request<CheckBlockIsCurrentChainRequest>,
+    ) -> Result<Response<CheckBlockIsCurrentChainResponse>, Status> {
+        self.check().await?;
+        let len = self.state().chain.len();
+        let is_part_of_current_chain = request
+            .into_inner()
+            .block_i_ds
+            .iter()
+            .all(|id| (*id as usize) < len);
+        Ok(Response::new(CheckBlockIsCurrentChainResponse {
+            is_part_of_current_chain,
+        }))
+    }
+
+    async fn get_chain_tips(
+        &self,
+        _: Request<()>,
+    ) -> Result<Response<GetChainTipsResponse>, Status> {
+        self.check().await?;
+        let tip = self.tip();
+        let mut tips = vec![ChainTip {
+            height: tip.block.height,
+            hash: tip.hash().to_string(),
+            branchlen: 0,
+            status: "active".to_string(),
+        }];
+
+        // A fork block is a tip unless another fork block builds on it
+        let side = self.state().side.clone();
+        for block in &side {
+            let hash = block.hash();
+            if side.iter().any(|b| b.block.header.prev_hash() == hash) {
+                continue;
+            }
+            let branchlen = self
+                .ancestors(&hash.raw, u64::MAX)?
+                .iter()
+                .take_while(|b| b.meta.id >= SIDE_BLOCK_ID_BASE)
+                .count() as u32;
+            let status = if block.meta.invalid {
+                "invalid"
+            } else {
+                "valid-fork"
+            };
+            tips.push(ChainTip {
+                height: block.block.height,
+                hash: hash.to_string(),
+                branchlen,
+                status: status.to_string(),
+            });
+        }
+        Ok(Response::new(GetChainTipsResponse { tips }))
+    }
+
+    async fn get_block_header(
+        &self,
+        request: Request<GetBlockHeaderRequest>,
+    ) -> Result<Response<GetBlockHeaderResponse>, Status> {
+        self.check().await?;
+        let block = self.find(&request.into_inner().block_hash)?;
+        Ok(Response::new(block.header_response()))
+    }
+
+    async fn invalidate_block(
+        &self,
+        request: Request<InvalidateBlockRequest>,
+    ) -> Result<Response<InvalidateBlockResponse>, Status> {
+        self.check().await?;
+        let hashes = self.set_invalid(&request.into_inner().block_hash, true)?;
+        Ok(Response::new(InvalidateBlockResponse {
+            invalidated_blocks: hashes.iter().map(hash_bytes).collect(),
+        }))
+    }
+
+    async fn revalidate_block(
+        &self,
+        request: Request<RevalidateBlockRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.check().await?;
+        self.set_invalid(&request.into_inner().block_hash, false)?;
+        Ok(Response::new(()))
+    }
+
+    type SubscribeStream = NotificationStream;
+
+    async fn subscribe(
+        &self,
+        _: Request<SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        self.check().await?;
+        let notifications = self.inner.notifications.subscribe();
+        let disconnect = self.inner.disconnect.subscribe();
+
+        let stream = futures::stream::unfold(
+            (notifications, disconnect),
+            |(mut notifications, mut disconnect)| async move {
+                loop {
+                    tokio::select! {
+                        _ = disconnect.recv() => return None,
+                        received = notifications.recv() => match received {
+                            Ok(notification) => {
+                                return Some((Ok(notification), (notifications, disconnect)))
+                            }
+                            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                            Err(broadcast::error::RecvError::Closed) => return None,
+                        },
+                    }
+                }
+            },
+        );
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn send_notification(
+        &self,
+        request: Request<Notification>,
+    ) -> Result<Response<()>, Status> {
+        self.check().await?;
+        self.notify(request.into_inner());
+        Ok(Response::new(()))
+    }
+
+    async fn get_state(
+        &self,
+        request: Request<GetStateRequest>,
+    ) -> Result<Response<StateResponse>, Status> {
+        self.check().await?;
+        let key = request.into_inner().key;
+        self.state()
+            .state
+            .get(&key)
+            .map(|data| Response::new(StateResponse { data: data.clone() }))
+            .ok_or_else(|| Status::not_found(format!("state key {} not found", key)))
+    }
+
+    async fn set_state(&self, request: Request<SetStateRequest>) -> Result<Response<()>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        self.state().state.insert(request.key, request.data);
+        Ok(Response::new(()))
+    }
+
+    async fn get_block_is_mined(
+        &self,
+        request: Request<GetBlockIsMinedRequest>,
+    ) -> Result<Response<GetBlockIsMinedResponse>, Status> {
+        self.check().await?;
+        let block = self.find(&request.into_inner().block_hash)?;
+        Ok(Response::new(GetBlockIsMinedResponse {
+            is_mined: block.meta.mined_set,
+        }))
+    }
+
+    async fn set_block_mined_set(
+        &self,
+        request: Request<SetBlockMinedSetRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.check().await?;
+        self.update_block(&request.into_inner().block_hash, |meta| {
+            meta.mined_set = true
+        })?;
+        Ok(Response::new(()))
+    }
+
+    async fn get_blocks_mined_not_set(
+        &self,
+        _: Request<()>,
+    ) -> Result<Response<GetBlocksMinedNotSetResponse>, Status> {
+        self.check().await?;
+        Ok(Response::new(GetBlocksMinedNotSetResponse {
+            block_bytes: self.blocks_where(|meta| !meta.mined_set),
+        }))
+    }
+
+    async fn set_block_subtrees_set(
+        &self,
+        request: Request<SetBlockSubtreesSetRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.check().await?;
+        self.update_block(&request.into_inner().block_hash, |meta| {
+            meta.subtrees_set = true
+        })?;
+        Ok(Response::new(()))
+    }
+
+    async fn get_blocks_subtrees_not_set(
+        &self,
+        _: Request<()>,
+    ) -> Result<Response<GetBlocksSubtreesNotSetResponse>, Status> {
+        self.check().await?;
+        Ok(Response::new(GetBlocksSubtreesNotSetResponse {
+            block_bytes: self.blocks_where(|meta| !meta.subtrees_set),
+        }))
+    }
+
+    async fn set_block_processed_at(
+        &self,
+        request: Request<SetBlockProcessedAtRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        let processed_at = (!request.clear).then(SystemTime::now);
+        self.update_block(&request.block_hash, |meta| meta.processed_at = processed_at)?;
+        Ok(Response::new(()))
+    }
+
+    async fn send_fsm_event(
+        &self,
+        request: Request<SendFsmEventRequest>,
+    ) -> Result<Response<GetFsmStateResponse>, Status> {
+        self.check().await?;
+        let event = match FsmEventType::try_from(request.into_inner().event) {
+            Ok(FsmEventType::Stop) => FsmEvent::Stop,
+            Ok(FsmEventType::Run) => FsmEvent::Run,
+            Ok(FsmEventType::Catchupblocks) => FsmEvent::CatchUpBlocks,
+            Ok(FsmEventType::Legacysync) => FsmEvent::LegacySync,
+            Err(_) => return Err(Status::invalid_argument("unknown FSM event")),
+        };
+        let state = self.transition(event);
+        Ok(Response::new(GetFsmStateResponse {
+            state: FsmStateType::from(state) as i32,
+        }))
+    }
+
+    async fn get_fsm_current_state(
+        &self,
+        _: Request<()>,
+    ) -> Result<Response<GetFsmStateResponse>, Status> {
+        self.check().await?;
+        Ok(Response::new(GetFsmStateResponse {
+            state: FsmStateType::from(self.fsm_state()) as i32,
+        }))
+    }
+
+    async fn wait_fsm_to_transition_to_given_state(
+        &self,
+        request: Request<WaitFsmToTransitionRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.check().await?;
+        let target = FsmState::try_from(request.into_inner().state)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        self.wait_for_state(|state| state == target).await?;
+        Ok(Response::new(()))
+    }
+
+    async fn wait_until_fsm_transition_from_idle_state(
+        &self,
+        _: Request<()>,
+    ) -> Result<Response<()>, Status> {
+        self.check().await?;
+        self.wait_for_state(|state| state != FsmState::Idle).await?;
+        Ok(Response::new(()))
+    }
+
+    async fn run(&self, _: Request<()>) -> Result<Response<()>, Status> {
+        self.check().await?;
+        self.transition(FsmEvent::Run);
+        Ok(Response::new(()))
+    }
+
+    async fn catch_up_blocks(&self, _: Request<()>) -> Result<Response<()>, Status> {
+        self.check().await?;
+        self.transition(FsmEvent::CatchUpBlocks);
+        Ok(Response::new(()))
+    }
+
+    async fn legacy_sync(&self, _: Request<()>) -> Result<Response<()>, Status> {
+        self.check().await?;
+        self.transition(FsmEvent::LegacySync);
+        Ok(Response::new(()))
+    }
+
+    async fn idle(&self, _: Request<()>) -> Result<Response<()>, Status> {
+        self.check().await?;
+        self.transition(FsmEvent::Stop);
+        Ok(Response::new(()))
+    }
+
+    async fn report_peer_failure(
+        &self,
+        _: Request<ReportPeerFailureRequest>,
+    ) -> Result<Response<()>, Status> {
+        unimplemented("ReportPeerFailure")
+    }
+
+    async fn get_block_locator(
+        &self,
+        request: Request<GetBlockLocatorRequest>,
+    ) -> Result<Response<GetBlockLocatorResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        let branch = self.ancestors(&request.hash, u64::MAX)?;
+        let tip_height = branch[0].block.height;
+        let locator = locator_heights(tip_height)
+            .into_iter()
+            .filter_map(|height| branch.get((tip_height - height) as usize))
+            .map(|b| hash_bytes(&b.hash()))
+            .collect();
+        Ok(Response::new(GetBlockLocatorResponse { locator }))
+    }
+
+    async fn locate_block_headers(
+        &self,
+        request: Request<LocateBlockHeadersRequest>,
+    ) -> Result<Response<LocateBlockHeadersResponse>, Status> {
+        self.check().await?;
+        let request = request.into_inner();
+        let state = self.state();
+
+        // Like a legacy peer, start after genesis if nothing in the locator is known
+        let start = request
+            .locator
+            .iter()
+            .find_map(|hash| {
+                state
+                    .chain
+                    .iter()
+                    .position(|b| b.hash().raw.as_slice() == hash.as_slice())
+            })
+            .unwrap_or(0)
+            + 1;
+        let max = match request.max_hashes {
+            0 => usize::MAX,
+            max => max as usize,
+        };
+
+        let mut block_headers = Vec::new();
+        for block in state.chain.iter().skip(start).take(max) {
+            block_headers.push(block.block.header.raw.to_vec());
+            if block.hash().raw.as_slice() == request.hash_stop.as_slice() {
+                break;
+            }
+        }
+        Ok(Response::new(LocateBlockHeadersResponse { block_headers }))
+    }
+
+    async fn get_best_height_and_time(
+        &self,
+        _: Request<()>,
+    ) -> Result<Response<GetBestHeightAndTimeResponse>, Status> {
+        self.check().await?;
+        let state = self.state();
+        let tip = state.chain.last().expect("chain always contains genesis");
+        let mut times: Vec<u32> = state
+            .chain
+            .iter()
+            .rev()
+            .take(11)
+            .map(|b| b.block.header.timestamp())
+            .collect();
+        times.sort_unstable();
+        Ok(Response::new(GetBestHeightAndTimeResponse {
+            height: tip.block.height,
+            time: times[times.len() / 2],
+        }))
+    }
+}
+
+#[tonic::async_trait]
+impl PeerService for MockTeranode {
+    async fn get_peers(&self, _: Request<()>) -> Result<Response<GetPeersResponse>, Status> {
+        self.check().await?;
+        Ok(Response::new(GetPeersResponse {
+            peers: self.state().peers.clone(),
+        }))
+    }
+
+    async fn ban_peer(
+        &self,
+        request: Request<BanPeerRequest>,
+    ) -> Result<Response<BanPeerResponse>, Status> {
+        self.check().await?;
+        self.state().banned.insert(request.into_inner().addr);
+        Ok(Response::new(BanPeerResponse { ok: true }))
+    }
+
+    async fn unban_peer(
+        &self,
+        request: Request<UnbanPeerRequest>,
+    ) -> Result<Response<UnbanPeerResponse>, Status> {
+        self.check().await?;
+        let ok = self.state().banned.remove(&request.into_inner().addr);
+        Ok(Response::new(UnbanPeerResponse { ok }))
+    }
+
+    async fn is_banned(
+        &self,
+        request: Request<IsBannedRequest>,
+    ) -> Result<Response<IsBannedResponse>, Status> {
+        self.check().await?;
+        let is_banned = self
+            .state()
+            .banned
+            .contains(&request.into_inner().ip_or_subnet);
+        Ok(Response::new(IsBannedResponse { is_banned }))
+    }
+
+    async fn list_banned(&self, _: Request<()>) -> Result<Response<ListBannedResponse>, Status> {
+        self.check().await?;
+        Ok(Response::new(ListBannedResponse {
+            banned: self.banned(),
+        }))
+    }
+
+    async fn clear_banned(&self, _: Request<()>) -> Result<Response<ClearBannedResponse>, Status> {
+        self.check().await?;
+        self.state().banned.clear();
+        Ok(Response::new(ClearBannedResponse { ok: true }))
+    }
+
+    async fn add_ban_score(
+        &self,
+        request: Request<AddBanScoreRequest>,
+    ) -> Result<Response<AddBanScoreResponse>, Status> {
+        self.check().await?;
+        let peer_id = request.into_inner().peer_id;
+        let ok = self.state().peers.iter().any(|p| p.id == peer_id);
+        Ok(Response::new(AddBanScoreResponse { ok }))
+    }
+
+    async fn connect_peer(
+        &self,
+        request: Request<ConnectPeerRequest>,
+    ) -> Result<Response<ConnectPeerResponse>, Status> {
+        self.check().await?;
+        let addr = request.into_inner().peer_address;
+        let id = addr.rsplit('/').next().unwrap_or_default().to_string();
+        let mut state = self.state();
+
+        let response = if state.peers.iter().any(|p| p.id == id) {
+            ConnectPeerResponse {
+                success: false,
+                error: format!("peer {} already connected", id),
+            }
+        } else {
+            state.peers.push(Peer {
+                id,
+                addr,
+                ..Default::default()
+            });
+            ConnectPeerResponse {
+                success: true,
+                error: String::new(),
+            }
+        };
+        Ok(Response::new(response))
+    }
+
+    async fn disconnect_peer(
+        &self,
+        request: Request<DisconnectPeerRequest>,
+    ) -> Result<Response<DisconnectPeerResponse>, Status> {
+        self.check().await?;
+        let peer_id = request.into_inner().peer_id;
+        let mut state = self.state();
+        let before = state.peers.len();
+        state.peers.retain(|p| p.id != peer_id);
+
+        let response = if state.peers.len() < before {
+            DisconnectPeerResponse {
+                success: true,
+                error: String::new(),
+            }
+        } else {
+            DisconnectPeerResponse {
+                success: false,
+                error: format!("peer {} not connected", peer_id),
+            }
+        };
+        Ok(Response::new(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IpSubnet, SubscriptionEvent, TeranodeClient, TeranodeError};
+    use futures::StreamExt;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_mined_chain_links() {
+        let mock = MockTeranode::with_blocks(5);
+        assert_eq!(mock.tip().block.height, 5);
+        for height in 1..=5 {
+            let block = mock.block_at(height).unwrap();
+            let parent = mock.block_at(height - 1).unwrap();
+            assert_eq!(block.block.header.prev_hash(), parent.hash());
+            assert_eq!(block.block.header.bits(), MOCK_BITS);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_against_mock() {
+        let mock = MockTeranode::with_blocks(10);
+        let server = mock.serve().await.unwrap();
+        let mut client = TeranodeClient::connect_with_endpoints(
+            Some(server.endpoint()),
+            Some(server.endpoint()),
+        )
+        .await
+        .unwrap();
+
+        let best = client.get_best_block_header().await.unwrap();
+        assert_eq!(best.height, 10);
+
+        let tip = mock.tip().hash();
+        let header = client.get_block_header(&tip).await.unwrap();
+        assert_eq!(header.meta.height, 10);
+
+        let headers = client.get_block_headers(&tip, 3).await.unwrap();
+        let heights: Vec<u32> = headers.iter().map(|h| h.meta.height).collect();
+        assert_eq!(heights, vec![10, 9, 8]);
+
+        let blocks = client.get_blocks_by_height(2, 4).await.unwrap();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].height, 2);
+
+        let missing = client.get_block(&Hash::from([0x11; 32])).await;
+        assert!(matches!(missing, Err(TeranodeError::NotFound(_))));
+
+        let addr: IpSubnet = "10.0.0.1".parse().unwrap();
+        let until = SystemTime::now() + Duration::from_secs(60);
+        client.ban_peer(addr, until).await.unwrap();
+        assert!(client.is_banned(addr).await.unwrap());
+        assert_eq!(mock.banned(), vec!["10.0.0.1".to_string()]);
+
+        server.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_retry_and_fsm_against_mock() {
+        let mock = MockTeranode::new();
+        let server = mock.serve().await.unwrap();
+        let mut client = TeranodeClient::connect(server.endpoint()).await.unwrap();
+
+        mock.fail_next(Status::unavailable("restarting"));
+        assert_eq!(client.get_best_block_header().await.unwrap().height, 0);
+
+        let timeout = Duration::from_secs(5);
+        mock.fail_next(Status::unavailable("restarting"));
+        assert_eq!(
+            client.get_fsm_current_state(timeout).await.unwrap(),
+            FsmState::Idle
+        );
+
+        // FSM events are not retried
+        mock.fail_next(Status::unavailable("restarting"));
+        client.run(timeout).await.unwrap_err();
+        assert_eq!(mock.fsm_state(), FsmState::Idle);
+        client.run(timeout).await.unwrap();
+        assert_eq!(mock.fsm_state(), FsmState::Running);
+    }
+
+    #[tokio::test]
+    async fn test_subscription_against_mock() {
+        let mock = MockTeranode::new();
+        let server = mock.serve().await.unwrap();
+        let mut client = TeranodeClient::connect(server.endpoint()).await.unwrap();
+        let mut events = Box::pin(client.subscribe("test").unwrap());
+
+        // Keep announcing blocks until the subscription has been established
+        let first = loop {
+            mock.mine_blocks(1);
+            if let Ok(Some(event)) =
+                tokio::time::timeout(Duration::from_millis(100), events.next()).await
+            {
+                break event;
+            }
+        };
+        assert!(matches!(first, SubscriptionEvent::Notification(ref n)
+            if n.notification_type == NotificationType::Block));
+    }
+}
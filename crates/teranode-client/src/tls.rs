@@ -0,0 +1,120 @@
+//! TLS settings for Teranode gRPC endpoints
+//!
+//! Production clusters usually sit behind TLS, sometimes requiring a client
+//! certificate as well. `https://` endpoints are verified against the system
+//! root store unless a custom CA bundle is given.
+
+use crate::error::{Result, TeranodeError};
+use std::path::{Path, PathBuf};
+use tonic::transport::{Certificate, ClientTlsConfig, Identity};
+
+/// TLS settings applied to `https://` endpoints
+///
+/// With the `serde` feature this can be read straight from a configuration
+/// file; every field is optional.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TlsConfig {
+    /// PEM bundle of CA certificates used instead of the system roots
+    pub ca_cert: Option<PathBuf>,
+
+    /// PEM client certificate for mutual TLS
+    pub client_cert: Option<PathBuf>,
+
+    /// PEM private key matching `client_cert`
+    pub client_key: Option<PathBuf>,
+
+    /// Server name to verify instead of the endpoint host (SNI override)
+    pub domain_name: Option<String>,
+}
+
+impl TlsConfig {
+    /// Set the CA bundle used to verify the server
+    pub fn with_ca_cert(mut self, path: impl Into<PathBuf>) -> Self {
+        self.ca_cert = Some(path.into());
+        self
+    }
+
+    /// Set the client certificate and key used for mutual TLS
+    pub fn with_client_identity(
+        mut self,
+        cert: impl Into<PathBuf>,
+        key: impl Into<PathBuf>,
+    ) -> Self {
+        self.client_cert = Some(cert.into());
+        self.client_key = Some(key.into());
+        self
+    }
+
+    /// Set the server name expected in the server's certificate
+    pub fn with_domain_name(mut self, domain_name: impl Into<String>) -> Self {
+        self.domain_name = Some(domain_name.into());
+        self
+    }
+
+    /// Load the referenced files and build the tonic TLS configuration
+    pub(crate) fn client_tls_config(&self) -> Result<ClientTlsConfig> {
+        let mut config = ClientTlsConfig::new();
+
+        config = match &self.ca_cert {
+            Some(path) => {
+                config.ca_certificate(Certificate::from_pem(read_pem(path, "CA certificate")?))
+            }
+            None => config.with_native_roots(),
+        };
+
+        match (&self.client_cert, &self.client_key) {
+            (Some(cert), Some(key)) => {
+                let cert = read_pem(cert, "client certificate")?;
+                let key = read_pem(key, "client key")?;
+                config = config.identity(Identity::from_pem(cert, key));
+            }
+            (None, None) => {}
+            _ => {
+                return Err(TeranodeError::ConfigError(
+                    "Client certificate and client key must be set together".to_string(),
+                ))
+            }
+        }
+
+        if let Some(domain_name) = &self.domain_name {
+            config = config.domain_name(domain_name);
+        }
+
+        Ok(config)
+    }
+}
+
+fn read_pem(path: &Path, what: &str) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| {
+        TeranodeError::ConfigError(format!("Failed to read {} {}: {}", what, path.display(), e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_tls_config() {
+        assert!(TlsConfig::default()
+            .with_domain_name("teranode.example.com")
+            .client_tls_config()
+            .is_ok());
+
+        let missing_key = TlsConfig {
+            client_cert: Some(PathBuf::from("client.pem")),
+            ..TlsConfig::default()
+        };
+        assert!(matches!(
+            missing_key.client_tls_config(),
+            Err(TeranodeError::ConfigError(_))
+        ));
+
+        let missing_ca = TlsConfig::default().with_ca_cert("/nonexistent/ca.pem");
+        assert!(matches!(
+            missing_ca.client_tls_config(),
+            Err(TeranodeError::ConfigError(ref m)) if m.contains("CA certificate")
+        ));
+    }
+}
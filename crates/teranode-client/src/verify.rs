@@ -0,0 +1,339 @@
+//! Header-chain verification
+//!
+//! Checks that a run of headers served by Teranode forms a consistent chain:
+//! each header links to the previous one, meets the proof of work its `bits`
+//! claim, is newer than the median time past, and carries the accumulated
+//! chain work that the metadata reports.
+//!
+//! Verification doesn't stop at the first problem; every violation found is
+//! reported so an audit can show the full extent of an inconsistency.
+
+use crate::header::{BlockHeaderMeta, HeaderWithMeta};
+use crate::work::{ChainWork, Target};
+use bitcoinsv::bitcoin::{BlockHash, BlockHeader};
+use std::collections::VecDeque;
+use thiserror::Error;
+
+/// Number of previous timestamps the median time past is taken over
+pub const MEDIAN_TIME_SPAN: usize = 11;
+
+/// A consistency rule broken by a header
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ChainViolation {
+    #[error("Block {hash} does not follow {expected}: prev_hash is {found}")]
+    BrokenLink {
+        hash: BlockHash,
+        expected: BlockHash,
+        found: BlockHash,
+    },
+
+    #[error("Block {hash} has invalid bits 0x{bits:08x}: {reason}")]
+    InvalidBits {
+        hash: BlockHash,
+        bits: u32,
+        reason: &'static str,
+    },
+
+    #[error("Block {hash} does not meet the target for bits 0x{bits:08x}")]
+    InsufficientWork { hash: BlockHash, bits: u32 },
+
+    #[error("Block {hash} timestamp {timestamp} is not after median time past {median_time_past}")]
+    TimestampTooOld {
+        hash: BlockHash,
+        timestamp: u32,
+        median_time_past: u32,
+    },
+
+    #[error("Block {hash} height is {found}, expected {expected}")]
+    HeightMismatch {
+        hash: BlockHash,
+        expected: u32,
+        found: u32,
+    },
+
+    #[error("Block {hash} chain work is 0x{reported}, expected 0x{expected}")]
+    ChainWorkMismatch {
+        hash: BlockHash,
+        expected: String,
+        reported: String,
+    },
+}
+
+/// Incremental verifier for a chain of headers, oldest first
+///
+/// The first header is taken as the anchor: its linkage can't be checked, and
+/// its reported chain work (if any) becomes the starting total. Timestamps
+/// are only checked once [`MEDIAN_TIME_SPAN`] earlier timestamps are known,
+/// unless they are supplied with [`with_previous_timestamps`].
+///
+/// [`with_previous_timestamps`]: HeaderChainVerifier::with_previous_timestamps
+#[derive(Debug, Clone, Default)]
+pub struct HeaderChainVerifier {
+    tip: Option<BlockHash>,
+    height: Option<u32>,
+    chain_work: Option<ChainWork>,
+    timestamps: VecDeque<u32>,
+}
+
+impl HeaderChainVerifier {
+    /// Create a verifier that anchors on the first header pushed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed the median-time-past window with the timestamps of the blocks
+    /// preceding the first header, oldest first
+    pub fn with_previous_timestamps(mut self, timestamps: impl IntoIterator<Item = u32>) -> Self {
+        for timestamp in timestamps {
+            self.record_timestamp(timestamp);
+        }
+        self
+    }
+
+    /// Hash of the last header pushed
+    pub fn tip(&self) -> Option<BlockHash> {
+        self.tip
+    }
+
+    /// Chain work accumulated up to the last header pushed, if known
+    pub fn chain_work(&self) -> Option<ChainWork> {
+        self.chain_work
+    }
+
+    /// Median of the last [`MEDIAN_TIME_SPAN`] timestamps, once that many are known
+    pub fn median_time_past(&self) -> Option<u32> {
+        if self.timestamps.len() < MEDIAN_TIME_SPAN {
+            return None;
+        }
+        let mut sorted: Vec<u32> = self.timestamps.iter().copied().collect();
+        sorted.sort_unstable();
+        Some(sorted[sorted.len() / 2])
+    }
+
+    /// Verify the next header, returning any rules it breaks
+    ///
+    /// # Arguments
+    /// * `header` - The header following the last one pushed
+    /// * `meta` - Teranode's metadata for the header, if available
+    pub fn push(
+        &mut self,
+        header: &BlockHeader,
+        meta: Option<&BlockHeaderMeta>,
+    ) -> Vec<ChainViolation> {
+        let hash = header.hash();
+        let mut violations = Vec::new();
+        let mut linked = self.tip.is_some();
+
+        if let Some(expected) = self.tip {
+            let found = header.prev_hash();
+            if found != expected {
+                linked = false;
+                violations.push(ChainViolation::BrokenLink {
+                    hash,
+                    expected,
+                    found,
+                });
+            }
+        }
+
+        let work = match Target::parse(header.bits()) {
+            Ok(target) => {
+                if !target.is_met_by(&hash) {
+                    violations.push(ChainViolation::InsufficientWork {
+                        hash,
+                        bits: header.bits(),
+                    });
+                }
+                Some(target.work())
+            }
+            Err(e) => {
+                violations.push(ChainViolation::InvalidBits {
+                    hash,
+                    bits: header.bits(),
+                    reason: e.reason(),
+                });
+                None
+            }
+        };
+
+        if let Some(median_time_past) = self.median_time_past() {
+            if header.timestamp() <= median_time_past {
+                violations.push(ChainViolation::TimestampTooOld {
+                    hash,
+                    timestamp: header.timestamp(),
+                    median_time_past,
+                });
+            }
+        }
+
+        // Accumulate the chain work expected at this header, if it is known.
+        // Across a broken link there is nothing meaningful to add to.
+        let expected_work = match (self.chain_work, work) {
+            (Some(total), Some(work)) if linked => total.checked_add(work),
+            _ => None,
+        };
+        let reported_work = meta
+            .filter(|m| !m.chain_work.is_empty())
+            .and_then(|m| ChainWork::from_be_bytes(&m.chain_work).ok());
+
+        if let (Some(expected), Some(meta)) = (expected_work, meta) {
+            if !meta.chain_work.is_empty() && reported_work != Some(expected) {
+                violations.push(ChainViolation::ChainWorkMismatch {
+                    hash,
+                    expected: format!("{:x}", expected),
+                    reported: match reported_work {
+                        Some(reported) => format!("{:x}", reported),
+                        None => format!("{:02x?}", meta.chain_work),
+                    },
+                });
+            }
+        }
+
+        if let (Some(previous), Some(meta)) = (self.height, meta) {
+            if meta.height != previous + 1 {
+                violations.push(ChainViolation::HeightMismatch {
+                    hash,
+                    expected: previous + 1,
+                    found: meta.height,
+                });
+            }
+        }
+
+        // Carry our own total forward so a single bad figure from the server
+        // is reported once rather than on every later header, resyncing to
+        // the server's figure after a broken link
+        self.chain_work = expected_work.or(reported_work);
+        self.height = meta.map(|m| m.height).or(self.height.map(|h| h + 1));
+        self.tip = Some(hash);
+        self.record_timestamp(header.timestamp());
+
+        violations
+    }
+
+    fn record_timestamp(&mut self, timestamp: u32) {
+        if self.timestamps.len() == MEDIAN_TIME_SPAN {
+            self.timestamps.pop_front();
+        }
+        self.timestamps.push_back(timestamp);
+    }
+}
+
+/// Verify headers with metadata, as returned by the `GetBlockHeaders*` calls
+///
+/// Headers must be ordered oldest first; `get_block_headers` walks backwards
+/// from its start hash, so reverse its result before verifying.
+pub fn verify_headers(headers: &[HeaderWithMeta]) -> Vec<ChainViolation> {
+    let mut verifier = HeaderChainVerifier::new();
+    headers
+        .iter()
+        .flat_map(|h| verifier.push(&h.header, Some(&h.meta)))
+        .collect()
+}
+
+/// Verify bare headers, as returned by `LocateBlockHeaders`, oldest first
+pub fn verify_raw_headers(headers: &[BlockHeader]) -> Vec<ChainViolation> {
+    let mut verifier = HeaderChainVerifier::new();
+    headers
+        .iter()
+        .flat_map(|h| verifier.push(h, None))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{mine_header, MockTeranode};
+    use bitcoinsv::bitcoin::Hash;
+
+    fn mock_chain(blocks: u32) -> Vec<HeaderWithMeta> {
+        let mock = MockTeranode::with_blocks(blocks);
+        (0..=blocks)
+            .map(|height| {
+                let block = mock.block_at(height).unwrap();
+                HeaderWithMeta {
+                    header: block.block.header,
+                    meta: block.meta,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_chain() {
+        let chain = mock_chain(15);
+        assert_eq!(verify_headers(&chain), vec![]);
+        assert_eq!(verify_headers(&chain[5..]), vec![]);
+
+        let raw: Vec<BlockHeader> = chain.into_iter().map(|h| h.header).collect();
+        assert_eq!(verify_raw_headers(&raw), vec![]);
+    }
+
+    #[test]
+    fn test_broken_link_and_height() {
+        let mut chain = mock_chain(4);
+        chain.remove(2);
+
+        let violations = verify_headers(&chain);
+        assert!(matches!(violations[0], ChainViolation::BrokenLink { .. }));
+        assert!(matches!(
+            violations[1],
+            ChainViolation::HeightMismatch {
+                expected: 2,
+                found: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_chain_work_mismatch() {
+        let mut chain = mock_chain(3);
+        chain[2].meta.chain_work = vec![0x07];
+
+        let violations = verify_headers(&chain);
+        let [ChainViolation::ChainWorkMismatch {
+            expected, reported, ..
+        }] = &violations[..]
+        else {
+            panic!("unexpected violations: {:?}", violations);
+        };
+        assert_eq!(reported, &format!("{:0>64}", "7"));
+        assert_eq!(expected.len(), reported.len());
+    }
+
+    #[test]
+    fn test_pow_and_timestamp() {
+        let chain = mock_chain(11);
+        let tip = &chain[11].header;
+
+        // A header dated before the median time past of the last 11 blocks
+        let stale = mine_header(&tip.hash(), &Hash::ZERO, chain[3].header.timestamp());
+        let mut verifier = HeaderChainVerifier::new();
+        for h in &chain {
+            assert!(verifier.push(&h.header, None).is_empty());
+        }
+        let violations = verifier.push(&stale, None);
+        assert!(matches!(
+            violations[..],
+            [ChainViolation::TimestampTooOld { .. }]
+        ));
+
+        // Claim a harder target than the header was mined at
+        let mut raw = stale.raw.to_vec();
+        raw[72..76].copy_from_slice(&0x1d00ffffu32.to_le_bytes());
+        let weak = BlockHeader::from_slice(&raw);
+        let violations = HeaderChainVerifier::new().push(&weak, None);
+        assert!(matches!(
+            violations[..],
+            [ChainViolation::InsufficientWork { .. }]
+        ));
+
+        raw[72..76].copy_from_slice(&0x04923456u32.to_le_bytes());
+        let negative = BlockHeader::from_slice(&raw);
+        let violations = HeaderChainVerifier::new().push(&negative, None);
+        assert!(matches!(
+            violations[..],
+            [ChainViolation::InvalidBits { .. }]
+        ));
+    }
+}
@@ -0,0 +1,531 @@
+//! Proof-of-work arithmetic
+//!
+//! Teranode reports difficulty as compact `bits` and accumulated chain work as
+//! big-endian bytes. [`Target`] and [`ChainWork`] parse these into 256-bit
+//! values that can be compared, summed and shown in human-readable form.
+
+use crate::error::{Result, TeranodeError};
+use crate::proto::blockchain_api::GetNextWorkRequiredResponse;
+use bitcoinsv::bitcoin::BlockHash;
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+/// Compact bits of the highest mainnet target, which defines difficulty 1
+pub const DIFFICULTY_1_BITS: u32 = 0x1d00ffff;
+
+/// A 256-bit unsigned integer, stored as little-endian 64-bit limbs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) struct U256([u64; 4]);
+
+impl U256 {
+    pub(crate) const ZERO: U256 = U256([0; 4]);
+    pub(crate) const ONE: U256 = U256([1, 0, 0, 0]);
+    pub(crate) const MAX: U256 = U256([u64::MAX; 4]);
+
+    /// Interpret 32 little-endian bytes, the byte order of a raw block hash
+    pub(crate) fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().expect("8-byte chunk"));
+        }
+        U256(limbs)
+    }
+
+    /// Interpret up to 32 big-endian bytes, as Teranode encodes chain work
+    ///
+    /// Returns `None` if the value doesn't fit in 256 bits.
+    pub(crate) fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        let significant = &bytes[start..];
+        if significant.len() > 32 {
+            return None;
+        }
+
+        let mut le = [0u8; 32];
+        for (i, byte) in significant.iter().rev().enumerate() {
+            le[i] = *byte;
+        }
+        Some(Self::from_le_bytes(le))
+    }
+
+    /// Encode as 32 big-endian bytes
+    pub(crate) fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, limb) in self.0.iter().rev().enumerate() {
+            bytes[i * 8..(i + 1) * 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    /// Number of significant bits
+    pub(crate) fn bits(&self) -> u32 {
+        for (i, limb) in self.0.iter().enumerate().rev() {
+            if *limb != 0 {
+                return 64 * i as u32 + (64 - limb.leading_zeros());
+            }
+        }
+        0
+    }
+
+    fn bit(&self, n: u32) -> bool {
+        self.0[(n / 64) as usize] >> (n % 64) & 1 == 1
+    }
+
+    pub(crate) fn checked_add(self, other: U256) -> Option<U256> {
+        let mut limbs = [0u64; 4];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = c1 || c2;
+        }
+        (!carry).then_some(U256(limbs))
+    }
+
+    pub(crate) fn wrapping_sub(self, other: U256) -> U256 {
+        let mut limbs = [0u64; 4];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            *limb = diff;
+            borrow = b1 || b2;
+        }
+        U256(limbs)
+    }
+
+    pub(crate) fn shl(self, shift: u32) -> U256 {
+        if shift >= 256 {
+            return U256::ZERO;
+        }
+        let (words, bits) = ((shift / 64) as usize, shift % 64);
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate().skip(words) {
+            *limb = self.0[i - words] << bits;
+            if bits > 0 && i > words {
+                *limb |= self.0[i - words - 1] >> (64 - bits);
+            }
+        }
+        U256(limbs)
+    }
+
+    pub(crate) fn shr(self, shift: u32) -> U256 {
+        if shift >= 256 {
+            return U256::ZERO;
+        }
+        let (words, bits) = ((shift / 64) as usize, shift % 64);
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate().take(4 - words) {
+            *limb = self.0[i + words] >> bits;
+            if bits > 0 && i + words < 3 {
+                *limb |= self.0[i + words + 1] << (64 - bits);
+            }
+        }
+        U256(limbs)
+    }
+
+    /// Integer division, returning `None` when dividing by zero
+    pub(crate) fn checked_div(self, divisor: U256) -> Option<U256> {
+        self.div_rem(divisor).map(|(quotient, _)| quotient)
+    }
+
+    /// Quotient and remainder, or `None` when dividing by zero
+    pub(crate) fn div_rem(self, divisor: U256) -> Option<(U256, U256)> {
+        if divisor.is_zero() {
+            return None;
+        }
+
+        let mut quotient = U256::ZERO;
+        let mut remainder = U256::ZERO;
+        for n in (0..self.bits()).rev() {
+            remainder = remainder.shl(1);
+            if self.bit(n) {
+                remainder.0[0] |= 1;
+            }
+            if remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient.0[(n / 64) as usize] |= 1 << (n % 64);
+            }
+        }
+        Some((quotient, remainder))
+    }
+
+    pub(crate) fn low_u64(&self) -> u64 {
+        self.0[0]
+    }
+
+    /// Nearest `f64`, losing precision beyond 53 significant bits
+    pub(crate) fn to_f64(self) -> f64 {
+        self.0
+            .iter()
+            .rev()
+            .fold(0.0, |acc, limb| acc * 18446744073709551616.0 + *limb as f64)
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        U256([value, 0, 0, 0])
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::LowerHex for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.to_be_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Why a compact `bits` value doesn't encode a usable target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompactError {
+    Negative,
+    Overflow,
+    Zero,
+}
+
+impl CompactError {
+    pub(crate) fn reason(&self) -> &'static str {
+        match self {
+            CompactError::Negative => "negative target",
+            CompactError::Overflow => "target overflows 256 bits",
+            CompactError::Zero => "zero target",
+        }
+    }
+}
+
+/// Expand compact `bits` into the full target
+fn target_from_compact(bits: u32) -> std::result::Result<U256, CompactError> {
+    let exponent = bits >> 24;
+    let mantissa = bits & 0x007f_ffff;
+
+    if mantissa != 0 && bits & 0x0080_0000 != 0 {
+        return Err(CompactError::Negative);
+    }
+    if mantissa != 0
+        && (exponent > 34
+            || (mantissa > 0xff && exponent > 33)
+            || (mantissa > 0xffff && exponent > 32))
+    {
+        return Err(CompactError::Overflow);
+    }
+
+    let target = if exponent <= 3 {
+        U256::from((mantissa >> (8 * (3 - exponent))) as u64)
+    } else {
+        U256::from(mantissa as u64).shl(8 * (exponent - 3))
+    };
+
+    if target.is_zero() {
+        Err(CompactError::Zero)
+    } else {
+        Ok(target)
+    }
+}
+
+/// Expected number of hashes needed to meet `target`: `2^256 / (target + 1)`
+fn work_for_target(target: U256) -> U256 {
+    // 2^256 doesn't fit, but 2^256 / (t + 1) == !t / (t + 1) + 1
+    match target.checked_add(U256::ONE) {
+        Some(divisor) => (!target)
+            .checked_div(divisor)
+            .unwrap_or(U256::ZERO)
+            .checked_add(U256::ONE)
+            .unwrap_or(U256::MAX),
+        None => U256::ONE,
+    }
+}
+
+impl std::ops::Not for U256 {
+    type Output = U256;
+
+    fn not(self) -> U256 {
+        U256(self.0.map(|limb| !limb))
+    }
+}
+
+/// A proof-of-work target: a block hash must not exceed it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Target(U256);
+
+impl Target {
+    /// The difficulty 1 target
+    pub fn difficulty_1() -> Self {
+        Self::from_compact(DIFFICULTY_1_BITS).expect("valid difficulty 1 bits")
+    }
+
+    /// Expand compact `bits`, as found in a block header
+    pub fn from_compact(bits: u32) -> Result<Self> {
+        Self::parse(bits).map_err(|e| {
+            TeranodeError::DecodeError(format!("Invalid bits 0x{:08x}: {}", bits, e.reason()))
+        })
+    }
+
+    pub(crate) fn parse(bits: u32) -> std::result::Result<Self, CompactError> {
+        target_from_compact(bits).map(Target)
+    }
+
+    /// Parse compact bits encoded as 4 little-endian bytes, as Teranode
+    /// returns them in `GetNextWorkRequiredResponse` and `SuitableBlock`
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self> {
+        let bits: [u8; 4] = bytes.try_into().map_err(|_| {
+            TeranodeError::DecodeError(format!("Invalid bits length: {}", bytes.len()))
+        })?;
+        Self::from_compact(u32::from_le_bytes(bits))
+    }
+
+    /// Encode as compact bits, truncating to the 23-bit mantissa
+    pub fn to_compact(&self) -> u32 {
+        let mut size = self.0.bits().div_ceil(8);
+        let mut mantissa = if size <= 3 {
+            (self.0.low_u64() << (8 * (3 - size))) as u32
+        } else {
+            self.0.shr(8 * (size - 3)).low_u64() as u32
+        };
+
+        // The top mantissa bit is a sign bit, so shift it out of the way
+        if mantissa & 0x0080_0000 != 0 {
+            mantissa >>= 8;
+            size += 1;
+        }
+        mantissa | (size << 24)
+    }
+
+    /// The target as 32 big-endian bytes
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+
+    /// Difficulty relative to the difficulty 1 target
+    pub fn difficulty(&self) -> f64 {
+        Self::difficulty_1().0.to_f64() / self.0.to_f64()
+    }
+
+    /// Expected number of hashes needed to find a block meeting this target
+    pub fn work(&self) -> ChainWork {
+        ChainWork(work_for_target(self.0))
+    }
+
+    /// Whether a block with this hash meets the target
+    pub fn is_met_by(&self, hash: &BlockHash) -> bool {
+        U256::from_le_bytes(hash.raw) <= self.0
+    }
+}
+
+impl TryFrom<&GetNextWorkRequiredResponse> for Target {
+    type Error = TeranodeError;
+
+    fn try_from(response: &GetNextWorkRequiredResponse) -> Result<Self> {
+        Self::from_compact_bytes(&response.bits)
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:x}", self.0)
+    }
+}
+
+/// Accumulated proof of work, measured in expected hashes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChainWork(U256);
+
+impl ChainWork {
+    pub const ZERO: ChainWork = ChainWork(U256::ZERO);
+
+    /// Parse big-endian bytes, as in `GetBlockHeaderResponse.chain_work`
+    pub fn from_be_bytes(bytes: &[u8]) -> Result<Self> {
+        U256::from_be_slice(bytes).map(ChainWork).ok_or_else(|| {
+            TeranodeError::DecodeError(format!("Chain work too large: {} bytes", bytes.len()))
+        })
+    }
+
+    /// The work as 32 big-endian bytes
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+
+    /// Sum of the work, or `None` if it overflows 256 bits
+    ///
+    /// Prefer this to `+` for values that come from a node.
+    pub fn checked_add(self, other: ChainWork) -> Option<ChainWork> {
+        self.0.checked_add(other.0).map(ChainWork)
+    }
+
+    /// Difference in work, or zero if `other` has more
+    pub fn saturating_sub(self, other: ChainWork) -> ChainWork {
+        if other.0 >= self.0 {
+            ChainWork::ZERO
+        } else {
+            ChainWork(self.0.wrapping_sub(other.0))
+        }
+    }
+
+    /// Base-2 logarithm of the work, the usual way to show it compactly
+    pub fn log2(&self) -> f64 {
+        self.0.to_f64().log2()
+    }
+
+    /// Approximate work as a float
+    pub fn to_f64(&self) -> f64 {
+        self.0.to_f64()
+    }
+}
+
+/// # Panics
+///
+/// Panics if the sum overflows 256 bits; use [`ChainWork::checked_add`] for
+/// untrusted values.
+impl Add for ChainWork {
+    type Output = ChainWork;
+
+    fn add(self, other: ChainWork) -> ChainWork {
+        self.checked_add(other).expect("chain work overflow")
+    }
+}
+
+impl AddAssign for ChainWork {
+    fn add_assign(&mut self, other: ChainWork) {
+        *self = *self + other;
+    }
+}
+
+impl Sum for ChainWork {
+    fn sum<I: Iterator<Item = ChainWork>>(iter: I) -> Self {
+        iter.fold(ChainWork::ZERO, Add::add)
+    }
+}
+
+/// Decimal number of expected hashes
+impl fmt::Display for ChainWork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Peel off 19 decimal digits at a time, the most a u64 holds
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+        let mut chunks = Vec::new();
+        let mut rest = self.0;
+        loop {
+            let (quotient, remainder) = rest.div_rem(U256::from(CHUNK)).expect("non-zero");
+            chunks.push(remainder.low_u64());
+            if quotient.is_zero() {
+                break;
+            }
+            rest = quotient;
+        }
+
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{:019}", chunk));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl fmt::LowerHex for ChainWork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_from_compact() {
+        let target = target_from_compact(0x1d00ffff).unwrap();
+        let mut expected = [0u8; 32];
+        expected[4] = 0xff;
+        expected[5] = 0xff;
+        assert_eq!(target.to_be_bytes(), expected);
+
+        let regtest = target_from_compact(0x207fffff).unwrap();
+        assert_eq!(regtest.to_be_bytes()[..3], [0x7f, 0xff, 0xff]);
+
+        assert_eq!(target_from_compact(0x04923456), Err(CompactError::Negative));
+        assert_eq!(target_from_compact(0xff123456), Err(CompactError::Overflow));
+        assert_eq!(target_from_compact(0x01003456), Err(CompactError::Zero));
+    }
+
+    #[test]
+    fn test_work_for_target() {
+        // Difficulty 1 on mainnet is 0x100010001 expected hashes
+        let work = work_for_target(target_from_compact(0x1d00ffff).unwrap());
+        assert_eq!(work, U256::from(0x1_0001_0001));
+
+        // Regtest minimum difficulty
+        let work = work_for_target(target_from_compact(0x207fffff).unwrap());
+        assert_eq!(work, U256::from(2));
+    }
+
+    #[test]
+    fn test_u256_encoding() {
+        let value =
+            U256::from_be_slice(&[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]).unwrap();
+        assert_eq!(value, U256::from(2).checked_add(U256::ONE.shl(64)).unwrap());
+        assert_eq!(U256::from_be_slice(&value.to_be_bytes()), Some(value));
+        assert_eq!(U256::from_be_slice(&[0u8; 40]), Some(U256::ZERO));
+        assert_eq!(U256::from_be_slice(&[1u8; 33]), None);
+        assert!(U256::ONE.shl(200) > U256::from(u64::MAX));
+        assert_eq!(value.shl(8).shr(8), value);
+    }
+
+    #[test]
+    fn test_target_conversions() {
+        for bits in [DIFFICULTY_1_BITS, 0x207fffff, 0x18034a9a, 0x03123456] {
+            assert_eq!(Target::from_compact(bits).unwrap().to_compact(), bits);
+        }
+        assert_eq!(Target::difficulty_1().difficulty(), 1.0);
+
+        let target = Target::from_compact(0x1b0404cb).unwrap();
+        assert!((target.difficulty() - 16307.420938523983).abs() < 1e-6);
+        assert!(Target::from_compact(0x04923456).is_err());
+
+        let response = GetNextWorkRequiredResponse {
+            bits: 0x207fffffu32.to_le_bytes().to_vec(),
+        };
+        let target = Target::try_from(&response).unwrap();
+        assert_eq!(target.work(), ChainWork(U256::from(2)));
+        assert!(Target::from_compact_bytes(&[0xff; 3]).is_err());
+    }
+
+    #[test]
+    fn test_chain_work() {
+        let work = Target::difficulty_1().work();
+        let total: ChainWork = std::iter::repeat_n(work, 3).sum();
+        assert_eq!(total.to_string(), "12885098499");
+        assert_eq!(format!("{:x}", total), format!("{:064x}", 0x3_0003_0003u64));
+        assert!(total > work);
+        assert_eq!(total.saturating_sub(work), work + work);
+        assert_eq!(work.saturating_sub(total), ChainWork::ZERO);
+
+        let parsed = ChainWork::from_be_bytes(&total.to_be_bytes()).unwrap();
+        assert_eq!(parsed, total);
+
+        let huge = ChainWork(U256::ONE.shl(100));
+        assert_eq!(huge.to_string(), "1267650600228229401496703205376");
+        assert_eq!(huge.log2(), 100.0);
+        assert_eq!(ChainWork(U256::MAX).checked_add(work), None);
+    }
+}
@@ -3,6 +3,7 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use teranode_client::TlsConfig;
 use tracing::{debug, info};
 
 /// Configuration structure for tnode-lab
@@ -14,8 +15,20 @@ pub struct Config {
     /// Peer service endpoint
     pub peer_endpoint: Option<String>,
 
+    /// Connect and request timeout in seconds
+    pub timeout: Option<u64>,
+
+    /// Bearer token sent with every request
+    pub auth_token: Option<String>,
+
     /// Enable verbose logging
     pub verbose: Option<bool>,
+
+    /// TLS settings; when present, endpoints without a scheme use https
+    ///
+    /// Paths point to PEM files. Leaving `ca_cert` unset verifies the server
+    /// against the system root certificates.
+    pub tls: Option<TlsConfig>,
 }
 
 impl Config {
@@ -77,9 +90,18 @@ impl Config {
         if other.peer_endpoint.is_some() {
             self.peer_endpoint = other.peer_endpoint.clone();
         }
+        if other.timeout.is_some() {
+            self.timeout = other.timeout;
+        }
+        if other.auth_token.is_some() {
+            self.auth_token = other.auth_token.clone();
+        }
         if other.verbose.is_some() {
             self.verbose = other.verbose;
         }
+        if other.tls.is_some() {
+            self.tls = other.tls.clone();
+        }
     }
 }
 
@@ -114,18 +136,46 @@ verbose: true
         let mut base = Config {
             blockchain_endpoint: Some("127.0.0.1:8087".to_string()),
             peer_endpoint: None,
+            timeout: None,
+            auth_token: None,
             verbose: Some(false),
+            tls: None,
         };
 
         let override_config = Config {
             blockchain_endpoint: Some("127.0.0.1:9000".to_string()),
             peer_endpoint: None,
+            timeout: Some(10),
+            auth_token: None,
             verbose: None,
+            tls: None,
         };
 
         base.merge(&override_config);
 
         assert_eq!(base.blockchain_endpoint, Some("127.0.0.1:9000".to_string()));
+        assert_eq!(base.timeout, Some(10));
         assert_eq!(base.verbose, Some(false)); // Not overridden
     }
+
+    #[test]
+    fn test_config_parse_tls() {
+        let yaml = r#"
+blockchain_endpoint: "teranode.example.com:8087"
+tls:
+  ca_cert: "/etc/tnode/ca.pem"
+  client_cert: "/etc/tnode/client.pem"
+  client_key: "/etc/tnode/client.key"
+  domain_name: "blockchain.teranode.internal"
+"#;
+
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let tls = config.tls.unwrap();
+        assert_eq!(tls.ca_cert, Some(PathBuf::from("/etc/tnode/ca.pem")));
+        assert_eq!(tls.client_key, Some(PathBuf::from("/etc/tnode/client.key")));
+        assert_eq!(
+            tls.domain_name,
+            Some("blockchain.teranode.internal".to_string())
+        );
+    }
 }
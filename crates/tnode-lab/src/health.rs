@@ -0,0 +1,222 @@
+//! Nagios-style health checks for `tnode health`
+//!
+//! Each check ends in one of the four plugin states, and the command exits
+//! with the code of the worst one so it can run under Nagios, Icinga or a
+//! plain cron job.
+
+use std::fmt;
+use std::process::ExitCode;
+use std::time::Duration;
+use teranode_client::{FsmState, Health};
+
+/// Outcome of a check, ordered from best to worst
+///
+/// UNKNOWN ranks between OK and WARNING, as in the Nagios plugin guidelines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Ok,
+    Unknown,
+    Warning,
+    Critical,
+}
+
+impl Status {
+    /// Plugin exit code for the status
+    pub fn code(self) -> u8 {
+        match self {
+            Status::Ok => 0,
+            Status::Warning => 1,
+            Status::Critical => 2,
+            Status::Unknown => 3,
+        }
+    }
+
+    /// Process exit code for the status
+    pub fn exit_code(self) -> ExitCode {
+        ExitCode::from(self.code())
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Ok => "OK",
+            Status::Unknown => "UNKNOWN",
+            Status::Warning => "WARNING",
+            Status::Critical => "CRITICAL",
+        })
+    }
+}
+
+/// Result of one check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub message: String,
+}
+
+impl Check {
+    pub fn new(name: &'static str, status: Status, message: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// Results of every check that ran
+#[derive(Debug, Default)]
+pub struct Report {
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    pub fn push(&mut self, check: Check) {
+        self.checks.push(check);
+    }
+
+    /// The worst status of any check, OK when none ran
+    pub fn status(&self) -> Status {
+        self.checks
+            .iter()
+            .map(|c| c.status)
+            .max()
+            .unwrap_or(Status::Ok)
+    }
+
+    /// One-line plugin output, e.g. "TNODE OK - blockchain: healthy; peers: 8 connected"
+    pub fn summary(&self) -> String {
+        let checks: Vec<String> = self
+            .checks
+            .iter()
+            .map(|c| format!("{}: {}", c.name, c.message))
+            .collect();
+        format!("TNODE {} - {}", self.status(), checks.join("; "))
+    }
+}
+
+/// Thresholds on the age of the tip block
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TipAgeLimits {
+    pub warning: Option<Duration>,
+    pub critical: Option<Duration>,
+}
+
+/// Check the blockchain service's own health report
+///
+/// Failing dependencies are named when the details can be decoded.
+pub fn check_blockchain(health: &Health) -> Check {
+    if health.ok {
+        return Check::new("blockchain", Status::Ok, "healthy");
+    }
+
+    let failing: Vec<String> = health
+        .dependencies()
+        .map(|deps| {
+            deps.into_iter()
+                .filter(|d| !d.is_ok())
+                .map(|d| format!("{} ({})", d.resource, d.status))
+                .collect()
+        })
+        .unwrap_or_default();
+    let message = if failing.is_empty() {
+        format!("unhealthy: {}", health.details)
+    } else {
+        format!("unhealthy: {}", failing.join(", "))
+    };
+    Check::new("blockchain", Status::Critical, message)
+}
+
+/// Check the age of the tip block against the limits
+pub fn check_tip_age(height: u32, age: Duration, limits: TipAgeLimits) -> Check {
+    let status = if limits.critical.is_some_and(|limit| age > limit) {
+        Status::Critical
+    } else if limits.warning.is_some_and(|limit| age > limit) {
+        Status::Warning
+    } else {
+        Status::Ok
+    };
+    Check::new(
+        "tip",
+        status,
+        format!("height {}, {}s old", height, age.as_secs()),
+    )
+}
+
+/// Check that the FSM is RUNNING
+///
+/// Syncing states are expected after a restart or a reorg, so they only warn;
+/// a node left IDLE is not processing blocks at all.
+pub fn check_fsm(state: FsmState) -> Check {
+    let status = match state {
+        FsmState::Running => Status::Ok,
+        FsmState::CatchingBlocks | FsmState::LegacySyncing => Status::Warning,
+        FsmState::Idle => Status::Critical,
+    };
+    Check::new("fsm", status, state.to_string())
+}
+
+/// Check the number of connected peers
+pub fn check_peers(count: usize) -> Check {
+    if count == 0 {
+        Check::new("peers", Status::Warning, "no peers connected")
+    } else {
+        Check::new("peers", Status::Ok, format!("{} connected", count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_status() {
+        let mut report = Report::default();
+        assert_eq!(report.status(), Status::Ok);
+
+        report.push(check_peers(3));
+        report.push(check_fsm(FsmState::Running));
+        assert_eq!(report.status(), Status::Ok);
+        assert_eq!(
+            report.summary(),
+            "TNODE OK - peers: 3 connected; fsm: RUNNING"
+        );
+
+        report.push(Check::new("tip", Status::Unknown, "no header"));
+        assert_eq!(report.status().code(), 3);
+        report.push(check_fsm(FsmState::CatchingBlocks));
+        assert_eq!(report.status(), Status::Warning);
+        report.push(check_fsm(FsmState::Idle));
+        assert_eq!(report.status().code(), 2);
+    }
+
+    #[test]
+    fn test_tip_age_limits() {
+        let limits = TipAgeLimits {
+            warning: Some(Duration::from_secs(600)),
+            critical: Some(Duration::from_secs(3600)),
+        };
+        let status = |secs| check_tip_age(1, Duration::from_secs(secs), limits).status;
+        assert_eq!(status(600), Status::Ok);
+        assert_eq!(status(601), Status::Warning);
+        assert_eq!(status(3601), Status::Critical);
+
+        let none = check_tip_age(1, Duration::from_secs(1 << 30), TipAgeLimits::default());
+        assert_eq!(none.status, Status::Ok);
+    }
+
+    #[test]
+    fn test_check_blockchain() {
+        let health = Health {
+            ok: false,
+            details: r#"{"status":"503","dependencies":[{"resource":"UtxoStore","status":"503"}]}"#
+                .to_string(),
+            timestamp: None,
+        };
+        let check = check_blockchain(&health);
+        assert_eq!(check.status, Status::Critical);
+        assert_eq!(check.message, "unhealthy: UtxoStore (503)");
+    }
+}
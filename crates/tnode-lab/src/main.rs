@@ -1,12 +1,22 @@
 //! Teranode CLI - Command-line tool for interacting with Teranode instances
 
 mod config;
+mod health;
 
-use anyhow::Result;
-use bitcoinsv::bitcoin::BlockHeader;
-use clap::{Parser, Subcommand};
+use anyhow::{bail, Context, Result};
+use bitcoinsv::bitcoin::{BlockHash, BlockHeader, FromHex};
+use clap::{Parser, Subcommand, ValueEnum};
 use config::Config;
-use tracing::info;
+use health::{Check, Report, Status, TipAgeLimits};
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+use teranode_client::backlog::block_age;
+use teranode_client::header::unix_time;
+use teranode_client::{
+    ChainWork, HeaderWithMeta, PendingBlocks, Target, TeranodeClient, TeranodeError, TlsConfig,
+};
+use tracing::{info, warn};
 
 #[derive(Parser)]
 #[command(name = "tnode")]
@@ -30,6 +40,16 @@ struct Cli {
     #[arg(short = 'p', long, env = "PEER_ENDPOINT")]
     peer_endpoint: Option<String>,
 
+    /// Timeout in seconds for connecting and for each request (default: 30)
+    /// Can be set via REQUEST_TIMEOUT environment variable or config file
+    #[arg(short = 't', long, env = "REQUEST_TIMEOUT")]
+    timeout: Option<u64>,
+
+    /// Bearer token sent with every request
+    /// Can be set via AUTH_TOKEN environment variable or config file
+    #[arg(long, env = "AUTH_TOKEN", hide_env_values = true)]
+    auth_token: Option<String>,
+
     /// Enable verbose logging
     #[arg(short, long, env = "VERBOSE")]
     verbose: bool,
@@ -46,6 +66,141 @@ enum Commands {
 
     /// Get the list of connected peers
     GetPeers,
+
+    /// List chain tips and where each competing branch forks from the active chain
+    #[command(alias = "getchaintips")]
+    ChainTips,
+
+    /// Mark a block and all of its descendants as invalid
+    #[command(alias = "invalidateblock")]
+    InvalidateBlock {
+        /// Hash of the block to invalidate
+        hash: String,
+
+        /// Skip the confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+
+    /// Clear the invalid mark from a block and its descendants
+    #[command(alias = "reconsiderblock")]
+    RevalidateBlock {
+        /// Hash of the block to revalidate
+        hash: String,
+
+        /// Skip the confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+
+    /// Summarize blocks waiting on mined or subtree processing
+    Backlog {
+        /// List every pending block
+        #[arg(short = 'l', long)]
+        list: bool,
+    },
+
+    /// Read and write the blockchain service's key/value state
+    State {
+        #[command(subcommand)]
+        command: StateCommand,
+    },
+
+    /// Check the blockchain and peer services, exiting with a Nagios plugin code
+    /// (0 OK, 1 WARNING, 2 CRITICAL, 3 UNKNOWN)
+    Health {
+        /// Warn when the tip block is older than this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        tip_warning: Option<u64>,
+
+        /// Fail when the tip block is older than this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        tip_critical: Option<u64>,
+
+        /// Flag an FSM that is not RUNNING
+        #[arg(long)]
+        fsm: bool,
+
+        /// Do not check the peer service
+        #[arg(long)]
+        skip_peers: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum StateCommand {
+    /// Print the value stored under a key
+    Get {
+        /// The state key
+        key: String,
+
+        /// How to display the value
+        #[arg(short = 'f', long, value_enum, default_value_t = ValueFormat::Hex)]
+        format: ValueFormat,
+    },
+
+    /// Store a value under a key, replacing any existing value
+    Set {
+        /// The state key
+        key: String,
+
+        /// The value to store
+        value: String,
+
+        /// How the value is given on the command line
+        #[arg(short = 'f', long, value_enum, default_value_t = ValueFormat::Text)]
+        format: ValueFormat,
+    },
+
+    /// Print the values stored under several keys
+    Dump {
+        /// The state keys; the service has no call to list them
+        #[arg(required = true)]
+        keys: Vec<String>,
+
+        /// How to display the values
+        #[arg(short = 'f', long, value_enum, default_value_t = ValueFormat::Hex)]
+        format: ValueFormat,
+    },
+}
+
+/// Encoding of a state value on the command line
+#[derive(Clone, Copy, ValueEnum)]
+enum ValueFormat {
+    /// Hex-encoded bytes
+    Hex,
+    /// UTF-8 text
+    Text,
+    /// JSON document
+    Json,
+}
+
+impl ValueFormat {
+    /// Render stored bytes for display
+    fn format(self, data: &[u8]) -> Result<String> {
+        Ok(match self {
+            ValueFormat::Hex => hex::encode(data),
+            ValueFormat::Text => String::from_utf8(data.to_vec()).context("Value is not UTF-8")?,
+            ValueFormat::Json => {
+                let value: serde_json::Value =
+                    serde_json::from_slice(data).context("Value is not JSON")?;
+                serde_json::to_string_pretty(&value)?
+            }
+        })
+    }
+
+    /// Parse a value given on the command line into the bytes to store
+    fn parse(self, value: &str) -> Result<Vec<u8>> {
+        Ok(match self {
+            ValueFormat::Hex => hex::decode(value).context("Value is not valid hex")?,
+            ValueFormat::Text => value.as_bytes().to_vec(),
+            ValueFormat::Json => {
+                let value: serde_json::Value =
+                    serde_json::from_str(value).context("Value is not valid JSON")?;
+                serde_json::to_vec(&value)?
+            }
+        })
+    }
 }
 
 /// Parse endpoint and add default port 8087 if not specified
@@ -66,6 +221,136 @@ fn parse_endpoint(endpoint: &str) -> String {
     }
 }
 
+/// Convert an endpoint to a URL, using https when TLS is configured
+fn endpoint_url(endpoint: &str, tls: bool) -> String {
+    let endpoint = parse_endpoint(endpoint);
+
+    if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
+        endpoint
+    } else if tls {
+        format!("https://{}", endpoint)
+    } else {
+        format!("http://{}", endpoint)
+    }
+}
+
+/// Settings shared by every connection the CLI makes
+struct ConnectOptions {
+    tls: Option<TlsConfig>,
+    timeout: Duration,
+    auth_token: Option<String>,
+}
+
+impl ConnectOptions {
+    /// Connect to the given services
+    async fn connect(
+        &self,
+        blockchain_endpoint: Option<&str>,
+        peer_endpoint: Option<&str>,
+    ) -> Result<TeranodeClient> {
+        let mut builder = TeranodeClient::builder()
+            .connect_timeout(self.timeout)
+            .request_timeout(self.timeout);
+
+        if let Some(endpoint) = blockchain_endpoint {
+            builder = builder.blockchain_endpoint(endpoint);
+        }
+        if let Some(endpoint) = peer_endpoint {
+            builder = builder.peer_endpoint(endpoint);
+        }
+        if let Some(tls) = &self.tls {
+            builder = builder.tls(tls.clone());
+        }
+        if let Some(token) = &self.auth_token {
+            builder = builder.bearer_token(token);
+        }
+
+        Ok(builder.connect().await?)
+    }
+}
+
+/// Parse a block hash given in the usual big-endian hex form
+fn parse_block_hash(hash: &str) -> Result<BlockHash> {
+    BlockHash::from_hex(hash).map_err(|_| anyhow::anyhow!("Invalid block hash: {}", hash))
+}
+
+/// List the blocks an invalidation or revalidation would affect
+fn print_affected(headers: &[HeaderWithMeta]) {
+    for header in headers {
+        let status = if header.meta.invalid {
+            " (invalid)"
+        } else {
+            ""
+        };
+        println!(
+            "  Height {}: {}{}",
+            header.meta.height,
+            header.hash(),
+            status
+        );
+    }
+}
+
+/// Ask the user to confirm an action by typing "yes"
+fn confirm(action: &str) -> Result<bool> {
+    print!("Type 'yes' to {}: ", action);
+    std::io::stdout().flush()?;
+
+    let mut answer = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut answer)
+        .context("Failed to read confirmation")?;
+    Ok(answer.trim() == "yes")
+}
+
+/// Summarize the blocks waiting on one processing step
+fn print_pending(title: &str, pending: &PendingBlocks, list: bool, now: SystemTime) {
+    println!("{}: {} blocks", title, pending.len());
+    if let Some((first, last)) = pending.height_range() {
+        println!("  Heights: {} - {}", first, last);
+    }
+    if let Some(oldest) = pending.oldest() {
+        println!(
+            "  Oldest: {} at height {}, {} old",
+            oldest.hash(),
+            oldest.height,
+            format_duration(block_age(oldest, now))
+        );
+    }
+    if list {
+        for block in &pending.blocks {
+            println!(
+                "  Height {}: {} ({} old)",
+                block.height,
+                block.hash(),
+                format_duration(block_age(block, now))
+            );
+        }
+    }
+}
+
+/// Format a duration as days, hours, minutes and seconds, e.g. "1h 2m 5s"
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (days, hours, minutes, seconds) = (
+        secs / 86400,
+        (secs % 86400) / 3600,
+        (secs % 3600) / 60,
+        secs % 60,
+    );
+
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
 /// Format Unix timestamp to human-readable date
 fn format_timestamp(timestamp: u32) -> String {
     use std::time::UNIX_EPOCH;
@@ -137,12 +422,28 @@ fn is_leap_year(year: u32) -> bool {
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> ExitCode {
     // Load .env file if it exists (doesn't error if missing)
     let _ = dotenvy::dotenv();
 
     let cli = Cli::parse();
+    let health = matches!(cli.command, Commands::Health { .. });
+
+    match run(cli).await {
+        Ok(code) => code,
+        // Monitoring treats any other code as a verdict on the node
+        Err(e) if health => {
+            println!("TNODE {} - {:#}", Status::Unknown, e);
+            Status::Unknown.exit_code()
+        }
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
 
+async fn run(cli: Cli) -> Result<ExitCode> {
     // Load configuration file
     // Precedence: CLI args > Environment variables > Config file > Defaults
     let config = if let Some(config_path) = &cli.config {
@@ -167,6 +468,17 @@ async fn main() -> Result<()> {
 
     let verbose = cli.verbose || config.as_ref().and_then(|c| c.verbose).unwrap_or(false);
 
+    let tls = config.as_ref().and_then(|c| c.tls.clone());
+
+    let timeout = cli
+        .timeout
+        .or_else(|| config.as_ref().and_then(|c| c.timeout))
+        .unwrap_or(30);
+
+    let auth_token = cli
+        .auth_token
+        .or_else(|| config.as_ref().and_then(|c| c.auth_token.clone()));
+
     // Initialize tracing
     let subscriber = tracing_subscriber::fmt()
         .with_max_level(if verbose {
@@ -177,22 +489,22 @@ async fn main() -> Result<()> {
         .finish();
     tracing::subscriber::set_global_default(subscriber)?;
 
-    // Parse endpoint and add default port if not specified
-    let endpoint = parse_endpoint(&blockchain_endpoint);
-
     // Convert IP:port to URL format for gRPC
-    let endpoint_url = if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
-        endpoint.clone()
-    } else {
-        format!("http://{}", endpoint)
+    let blockchain_url = endpoint_url(&blockchain_endpoint, tls.is_some());
+    let peer_url = endpoint_url(&peer_endpoint, tls.is_some());
+
+    let options = ConnectOptions {
+        tls,
+        timeout: Duration::from_secs(timeout),
+        auth_token,
     };
 
     info!("Teranode CLI starting");
-    info!("Connecting to endpoint: {}", endpoint_url);
+    info!("Connecting to endpoint: {}", blockchain_url);
 
     match cli.command {
         Commands::GetBestBlock => {
-            let mut client = teranode_client::TeranodeClient::connect(&endpoint_url).await?;
+            let mut client = options.connect(Some(&blockchain_url), None).await?;
             let response = client.get_best_block_header().await?;
 
             // Parse the block header
@@ -226,7 +538,14 @@ async fn main() -> Result<()> {
             // Display chain work
             if !response.chain_work.is_empty() {
                 let chain_work_hex = hex::encode(&response.chain_work);
-                println!("  Chain Work: 0x{}", chain_work_hex);
+                match ChainWork::from_be_bytes(&response.chain_work) {
+                    Ok(work) => println!(
+                        "  Chain Work: 0x{} (2^{:.2} hashes)",
+                        chain_work_hex,
+                        work.log2()
+                    ),
+                    Err(_) => println!("  Chain Work: 0x{}", chain_work_hex),
+                }
             }
 
             if let Some(processed_at) = response.processed_at {
@@ -245,28 +564,21 @@ async fn main() -> Result<()> {
                     header.timestamp(),
                     format_timestamp(header.timestamp())
                 );
-                println!("  Bits: 0x{:08x}", header.bits());
+                match Target::from_compact(header.bits()) {
+                    Ok(target) => println!(
+                        "  Bits: 0x{:08x} (difficulty {:.2})",
+                        header.bits(),
+                        target.difficulty()
+                    ),
+                    Err(_) => println!("  Bits: 0x{:08x} (invalid)", header.bits()),
+                }
                 println!("  Nonce: {}", header.nonce());
             }
         }
         Commands::GetPeers => {
-            // Parse peer endpoint
-            let peer_endpoint_parsed = parse_endpoint(&peer_endpoint);
-            let peer_endpoint_url = if peer_endpoint_parsed.starts_with("http://")
-                || peer_endpoint_parsed.starts_with("https://")
-            {
-                peer_endpoint_parsed.clone()
-            } else {
-                format!("http://{}", peer_endpoint_parsed)
-            };
+            info!("Connecting to peer service: {}", peer_url);
 
-            info!("Connecting to peer service: {}", peer_endpoint_url);
-
-            let mut client = teranode_client::TeranodeClient::connect_with_endpoints(
-                None::<String>,
-                Some(&peer_endpoint_url),
-            )
-            .await?;
+            let mut client = options.connect(None, Some(&peer_url)).await?;
 
             let response = client.get_peers().await?;
 
@@ -315,7 +627,198 @@ async fn main() -> Result<()> {
                 }
             }
         }
+        Commands::ChainTips => {
+            let mut client = options.connect(Some(&blockchain_url), None).await?;
+            let tips = client.get_chain_tips().await?;
+
+            println!("Chain Tips: {} total\n", tips.len());
+            for tip in tips {
+                println!("{} at height {} ({})", tip.hash, tip.height, tip.status);
+                if tip.is_active() {
+                    println!();
+                    continue;
+                }
+
+                let fork = client.analyze_fork(tip).await?;
+                println!(
+                    "  Fork Point: {} at height {}",
+                    fork.fork_point.hash(),
+                    fork.fork_height()
+                );
+                println!("  Branch Length: {}", fork.branch_len());
+                for (i, header) in fork.branch.iter().enumerate() {
+                    let competing = fork
+                        .competing
+                        .get(i)
+                        .map(|h| h.hash().to_string())
+                        .unwrap_or_else(|| "-".to_string());
+                    println!(
+                        "  Height {}: {} vs active {}",
+                        header.meta.height,
+                        header.hash(),
+                        competing
+                    );
+                }
+                println!();
+            }
+        }
+        Commands::InvalidateBlock { hash, yes } => {
+            let hash = parse_block_hash(&hash)?;
+            let mut client = options.connect(Some(&blockchain_url), None).await?;
+
+            let affected = client.get_block_and_descendants(&hash).await?;
+            println!("Blocks that will be marked invalid: {}", affected.len());
+            print_affected(&affected);
+            println!();
+
+            if !yes && !confirm("invalidate these blocks")? {
+                bail!("Aborted, no blocks were invalidated");
+            }
+
+            let invalidated = client.invalidate_block(&hash).await?;
+            warn!(
+                "Invalidated block {} and {} descendants",
+                hash,
+                invalidated.len().saturating_sub(1)
+            );
+            println!("Invalidated Blocks: {}", invalidated.len());
+            for hash in invalidated {
+                println!("  {}", hash);
+            }
+        }
+        Commands::RevalidateBlock { hash, yes } => {
+            let hash = parse_block_hash(&hash)?;
+            let mut client = options.connect(Some(&blockchain_url), None).await?;
+
+            let affected = client.get_block_and_descendants(&hash).await?;
+            println!("Blocks that will be revalidated: {}", affected.len());
+            print_affected(&affected);
+            println!();
+
+            if !yes && !confirm("revalidate these blocks")? {
+                bail!("Aborted, no blocks were revalidated");
+            }
+
+            client.revalidate_block(&hash).await?;
+            warn!("Revalidated block {}", hash);
+            println!("Revalidated block {}", hash);
+        }
+        Commands::Backlog { list } => {
+            let mut client = options.connect(Some(&blockchain_url), None).await?;
+            let backlog = client.get_backlog().await?;
+            let now = SystemTime::now();
+
+            print_pending("Mined Not Set", &backlog.mined_not_set, list, now);
+            print_pending("Subtrees Not Set", &backlog.subtrees_not_set, list, now);
+            if backlog.is_empty() {
+                println!("\nNo blocks waiting on processing.");
+            }
+        }
+        Commands::State { command } => {
+            let mut client = options.connect(Some(&blockchain_url), None).await?;
+
+            match command {
+                StateCommand::Get { key, format } => {
+                    let data = client.get_state(&key).await?;
+                    println!("{}", format.format(&data)?);
+                }
+                StateCommand::Set { key, value, format } => {
+                    let data = format.parse(&value)?;
+                    let len = data.len();
+                    client.set_state(&key, data).await?;
+                    info!("Set state {} ({} bytes)", key, len);
+                    println!("Stored {} bytes under {}", len, key);
+                }
+                StateCommand::Dump { keys, format } => {
+                    for key in keys {
+                        match client.get_state(&key).await {
+                            Ok(data) => println!("{}: {}", key, format.format(&data)?),
+                            Err(TeranodeError::NotFound(_)) => println!("{}: (not set)", key),
+                            Err(e) => return Err(e.into()),
+                        }
+                    }
+                }
+            }
+        }
+        Commands::Health {
+            tip_warning,
+            tip_critical,
+            fsm,
+            skip_peers,
+        } => {
+            let limits = TipAgeLimits {
+                warning: tip_warning.map(Duration::from_secs),
+                critical: tip_critical.map(Duration::from_secs),
+            };
+
+            let mut report = Report::default();
+            check_blockchain_service(&options, &blockchain_url, limits, fsm, &mut report).await;
+            if !skip_peers {
+                report.push(match options.connect(None, Some(&peer_url)).await {
+                    Ok(mut client) => match client.get_peers().await {
+                        Ok(response) => health::check_peers(response.peers.len()),
+                        Err(e) => Check::new("peers", Status::Critical, e.to_string()),
+                    },
+                    Err(e) => Check::new("peers", Status::Critical, format!("unreachable: {}", e)),
+                });
+            }
+
+            println!("{}", report.summary());
+            return Ok(report.status().exit_code());
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Run the blockchain service checks for `tnode health`
+///
+/// The tip and FSM checks only run once the service reports itself healthy;
+/// if they then fail to get an answer, their result is UNKNOWN.
+async fn check_blockchain_service(
+    options: &ConnectOptions,
+    url: &str,
+    limits: TipAgeLimits,
+    fsm: bool,
+    report: &mut Report,
+) {
+    let mut client = match options.connect(Some(url), None).await {
+        Ok(client) => client,
+        Err(e) => {
+            let message = format!("unreachable: {}", e);
+            return report.push(Check::new("blockchain", Status::Critical, message));
+        }
+    };
+
+    let check = match client.health().await {
+        Ok(health) => health::check_blockchain(&health),
+        Err(e) => Check::new("blockchain", Status::Critical, e.to_string()),
+    };
+    let healthy = check.status == Status::Ok;
+    report.push(check);
+    if !healthy {
+        return;
     }
 
-    Ok(())
+    report.push(
+        match client
+            .get_best_block_header()
+            .await
+            .and_then(HeaderWithMeta::try_from)
+        {
+            Ok(tip) => {
+                let time = unix_time(tip.header.timestamp());
+                let age = SystemTime::now().duration_since(time).unwrap_or_default();
+                health::check_tip_age(tip.meta.height, age, limits)
+            }
+            Err(e) => Check::new("tip", Status::Unknown, e.to_string()),
+        },
+    );
+
+    if fsm {
+        report.push(match client.get_fsm_current_state(options.timeout).await {
+            Ok(state) => health::check_fsm(state),
+            Err(e) => Check::new("fsm", Status::Unknown, e.to_string()),
+        });
+    }
 }
@@ -0,0 +1,171 @@
+//! End-to-end tests running the `tnode` binary against a mock Teranode
+
+use std::process::{Output, Stdio};
+use teranode_client::testing::MockTeranode;
+use teranode_client::FsmState;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// Run `tnode` with both endpoints pointed at `addr`, isolated from any
+/// config file or environment on the host
+async fn tnode(addr: &str, args: &[&str]) -> Output {
+    tnode_with_input(addr, args, "").await
+}
+
+/// Run `tnode` as above, feeding `input` to its stdin
+async fn tnode_with_input(addr: &str, args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tnode"))
+        .current_dir(std::env::temp_dir())
+        .env_clear()
+        .args(["-b", addr, "-p", addr, "-t", "5"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run tnode");
+
+    let mut stdin = child.stdin.take().unwrap();
+    stdin.write_all(input.as_bytes()).await.unwrap();
+    drop(stdin);
+    child.wait_with_output().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_get_best_block() {
+    let mock = MockTeranode::with_blocks(3);
+    let server = mock.serve().await.unwrap();
+
+    let output = tnode(&server.addr().to_string(), &["get-best-block"]).await;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Height: 3"));
+    assert!(stdout.contains(&mock.tip().hash().to_string()));
+}
+
+#[tokio::test]
+async fn test_unreachable_node_fails() {
+    let mock = MockTeranode::new();
+    let server = mock.serve().await.unwrap();
+    let addr = server.addr().to_string();
+    server.shutdown().await;
+
+    let output = tnode(&addr, &["get-best-block"]).await;
+    assert!(!output.status.success());
+}
+
+#[tokio::test]
+async fn test_chain_tips() {
+    let mock = MockTeranode::with_blocks(5);
+    let branch = mock.fork(3, 1);
+    let server = mock.serve().await.unwrap();
+
+    let output = tnode(&server.addr().to_string(), &["chain-tips"]).await;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Chain Tips: 2 total"));
+    assert!(stdout.contains(&format!("{} at height 4 (valid-fork)", branch[0])));
+    assert!(stdout.contains("Branch Length: 1"));
+}
+
+#[tokio::test]
+async fn test_invalidate_block_requires_confirmation() {
+    let mock = MockTeranode::with_blocks(5);
+    let server = mock.serve().await.unwrap();
+    let addr = server.addr().to_string();
+    let hash = mock.block_at(4).unwrap().hash().to_string();
+
+    let output = tnode_with_input(&addr, &["invalidate-block", &hash], "no\n").await;
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Blocks that will be marked invalid: 2"));
+    assert!(!mock.block_at(4).unwrap().meta.invalid);
+
+    let output = tnode_with_input(&addr, &["invalidate-block", &hash], "yes\n").await;
+    assert!(output.status.success());
+    assert!(mock.block_at(5).unwrap().meta.invalid);
+
+    let output = tnode(&addr, &["revalidate-block", "--yes", &hash]).await;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(&format!("Height 4: {} (invalid)", hash)));
+    assert!(!mock.block_at(5).unwrap().meta.invalid);
+}
+
+#[tokio::test]
+async fn test_state_set_get_dump() {
+    let mock = MockTeranode::new();
+    let server = mock.serve().await.unwrap();
+    let addr = server.addr().to_string();
+
+    let output = tnode(&addr, &["state", "set", "-f", "json", "cfg", r#"{"a": 1}"#]).await;
+    assert!(output.status.success());
+
+    let output = tnode(&addr, &["state", "get", "cfg", "--format", "text"]).await;
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "{\"a\":1}\n");
+
+    let output = tnode(&addr, &["state", "dump", "cfg", "missing"]).await;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("cfg: 7b2261223a317d"));
+    assert!(stdout.contains("missing: (not set)"));
+
+    let output = tnode(&addr, &["state", "get", "missing"]).await;
+    assert!(!output.status.success());
+}
+
+#[tokio::test]
+async fn test_backlog() {
+    let mock = MockTeranode::with_blocks(4);
+    mock.update_meta(2, |meta| meta.mined_set = false);
+    mock.update_meta(3, |meta| meta.mined_set = false);
+    let server = mock.serve().await.unwrap();
+
+    let output = tnode(&server.addr().to_string(), &["backlog", "--list"]).await;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Mined Not Set: 2 blocks"));
+    assert!(stdout.contains("Heights: 2 - 3"));
+    assert!(stdout.contains(&format!("Height 3: {}", mock.block_at(3).unwrap().hash())));
+    assert!(stdout.contains("Subtrees Not Set: 0 blocks"));
+}
+
+#[tokio::test]
+async fn test_health_exit_codes() {
+    let mock = MockTeranode::with_blocks(3);
+    mock.add_peer(Default::default());
+    let server = mock.serve().await.unwrap();
+    let addr = server.addr().to_string();
+
+    let output = tnode(&addr, &["health", "--fsm"]).await;
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.status.code(), Some(2), "{}", stdout);
+    assert!(stdout.starts_with("TNODE CRITICAL - blockchain: healthy; tip: height 3"));
+    assert!(stdout.contains("fsm: IDLE"));
+    assert!(stdout.contains("peers: 1 connected"));
+
+    mock.set_fsm_state(FsmState::Running);
+    let output = tnode(&addr, &["health", "--fsm"]).await;
+    assert_eq!(output.status.code(), Some(0));
+
+    // Mock block timestamps are years old
+    let output = tnode(&addr, &["health", "--tip-warning", "3600"]).await;
+    assert_eq!(output.status.code(), Some(1));
+
+    mock.set_healthy(false);
+    let output = tnode(&addr, &["health", "--skip-peers"]).await;
+    assert_eq!(output.status.code(), Some(2));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout,
+        "TNODE CRITICAL - blockchain: unhealthy: MockStore (503)\n"
+    );
+
+    server.shutdown().await;
+    let output = tnode(&addr, &["health"]).await;
+    assert_eq!(output.status.code(), Some(2));
+}